@@ -1,3 +1,7 @@
 mod basic_hash_string;
+mod param_repository;
+mod resource;
 
 pub use basic_hash_string::*;
+pub use param_repository::*;
+pub use resource::*;
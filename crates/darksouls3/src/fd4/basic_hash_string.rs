@@ -11,8 +11,12 @@ pub struct FD4BasicHashString<T: DLStringKind = DLUTF16StringKind> {
     vftable: usize,
     /// The contained string we're hashing for.
     pub inner: DLString<T>,
-    // The rest of this is probably the same as in ER, but this hasn't been
-    // verified yet.
+    // These are assumed to be the same as in ER, which matches the size
+    // FD4ResCap expects, but they haven't been verified yet.
+    /// Hashed representation of the string field.
+    pub hash: u32,
+    /// Indicates whether or not the hash field is populated.
+    pub needs_hashing: bool,
 }
 
 impl<T: DLStringKind> AsRef<DLString<T>> for FD4BasicHashString<T> {
@@ -0,0 +1,64 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use param_generator::Generator;
+
+/// Param tables that this crate's own definitions use or link to in their
+/// docs, which are generated even when their features aren't enabled.
+const REQUIRED_TABLES: &[&str] = &[
+    "ASSET_GEOMETORY_PARAM_ST",
+    "ATK_PARAM_ST",
+    "BUDDY_PARAM_ST",
+    "BUDDY_STONE_PARAM_ST",
+    "BULLET_PARAM_ST",
+    "CEREMONY_PARAM_ST",
+    "GAME_SYSTEM_COMMON_PARAM_ST",
+    "LOCK_CAM_PARAM_ST",
+    "NETWORK_MSG_PARAM_ST",
+    "NPC_PARAM_ST",
+    "SP_EFFECT_PARAM_ST",
+    "WHITE_SIGN_COOL_TIME_PARAM_ST",
+];
+
+/// The paramdex meta XML files, which provide enum values and references.
+const META: &str = "paramdex/Meta";
+
+/// The paramdex row names, one `<id> <name>` file per param.
+const NAMES: &str = "paramdex/Names";
+
+fn main() {
+    println!("cargo:rerun-if-changed=paramdex");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let mut generator = Generator::new("paramdex/Defs")
+        .repository("crate::fd4::FD4ParamRepository")
+        .tables_from_features(REQUIRED_TABLES.iter().copied());
+
+    // Meta and row names are optional, so the crate still builds before
+    // they have been vendored.
+    if Path::new(META).is_dir() {
+        generator = generator.meta(META);
+    } else {
+        warn_missing(META);
+    }
+    if Path::new(NAMES).is_dir() {
+        generator = generator.names(NAMES);
+    } else {
+        warn_missing(NAMES);
+    }
+
+    generator
+        .write(out_dir.join("param.rs"))
+        .expect("Failed to generate params");
+}
+
+/// Warns that [folder] hasn't been vendored, so the params are generated
+/// without the enums, ID types, or row names it provides.
+fn warn_missing(folder: &str) {
+    println!(
+        "cargo:warning={folder} is missing, so params are generated without it. Run \
+         tools/param-generator/update-paramdex.sh to vendor it."
+    );
+}
@@ -1,5 +1,6 @@
 mod basic_hash_string;
 mod param_repository;
+mod param_snapshot;
 mod resource;
 mod slot;
 mod step;
@@ -8,6 +9,7 @@ mod time;
 
 pub use basic_hash_string::*;
 pub use param_repository::*;
+pub use param_snapshot::*;
 pub use resource::*;
 pub use slot::*;
 pub use step::*;
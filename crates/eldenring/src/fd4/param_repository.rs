@@ -1,40 +1,159 @@
-use windows::core::PCSTR;
+use std::collections::BTreeMap;
+use std::ffi::CStr;
 
+use crate::dlkr::DLAllocatorRef;
 use crate::param::ParamDef;
-use shared::OwnedPtr;
+use shared::{OwnedPtr, ParamEditError, ParamHeader, ParamId, ParamResCap, ParamResCapHolder};
 
 use super::FD4ResRep;
-use super::resource::FD4ResCap;
+use super::resource::{FD4ResCap, FD4ResCapHolder};
 
 #[repr(C)]
 #[shared::singleton("FD4ParamRepository")]
 pub struct FD4ParamRepository {
     /// Resource repository holding the actual param data.
     pub res_rep: FD4ResRep<FD4ParamResCap>,
-    allocator: usize,
+    allocator: DLAllocatorRef,
 }
 
+/// A param file as the game holds it in memory.
+pub type ParamData = shared::ParamData<ParamFileHeader>;
+
+/// A param whose paramdef and row size have already been checked against
+/// [T], returned by [FD4ParamRepository::handle].
+pub type ParamHandle<'a, T> = shared::ParamHandle<'a, ParamFileHeader, T>;
+
 impl FD4ParamRepository {
+    /// Retrieve a row of the first param whose paramdef is [T].
+    ///
+    /// Returns `None` if the live param's rows aren't [ParamDef::ROW_SIZE]
+    /// bytes, since that means [T] doesn't match the game's paramdef.
     pub fn get<T: ParamDef>(&self, id: u32) -> Option<&T> {
-        let file_header = self
-            .res_rep
+        self.handle::<T>()?.get(id)
+    }
+
+    /// Retrieve a row of the first param whose paramdef is [T] mutably.
+    ///
+    /// Returns `None` if the live param's rows aren't [ParamDef::ROW_SIZE]
+    /// bytes, since that means [T] doesn't match the game's paramdef.
+    pub fn get_mut<T: ParamDef>(&mut self, id: u32) -> Option<&mut T> {
+        self.res_rep.res_cap_holder.get_mut::<T>(Some(T::INDEX), id)
+    }
+
+    /// Iterates over every row of the first param whose paramdef is [T],
+    /// yielding each row's ID, name, and data in ID order.
+    ///
+    /// Yields nothing if there's no such param or its rows aren't
+    /// [ParamDef::ROW_SIZE] bytes.
+    pub fn rows<'a, T: ParamDef + 'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (u32, Option<String>, &'a T)> {
+        self.handle::<T>()
+            .into_iter()
+            .flat_map(|handle| handle.rows())
+    }
+
+    /// Like [Self::rows], but yields mutable rows.
+    pub fn rows_mut<'a, T: ParamDef + 'a>(
+        &'a mut self,
+    ) -> impl Iterator<Item = (u32, Option<String>, &'a mut T)> {
+        self.res_rep.res_cap_holder.rows_mut::<T>(Some(T::INDEX))
+    }
+
+    /// Retrieve the first param whose paramdef is [T], if its rows are
+    /// [ParamDef::ROW_SIZE] bytes.
+    pub fn param<T: ParamDef>(&self) -> Option<&ParamData> {
+        self.res_rep.res_cap_holder.param::<T>(Some(T::INDEX))
+    }
+
+    /// Like [Self::param], but mutable.
+    pub fn param_mut<T: ParamDef>(&mut self) -> Option<&mut ParamData> {
+        self.res_rep.res_cap_holder.param_mut::<T>(Some(T::INDEX))
+    }
+
+    /// Finds the first param whose paramdef is [T] and returns a handle to
+    /// its rows.
+    ///
+    /// Each lookup finds the param and checks it against [T] again, so code
+    /// that reads the same param repeatedly, such as every frame, can save
+    /// that by holding onto a handle.
+    pub fn handle<T: ParamDef>(&self) -> Option<ParamHandle<'_, T>> {
+        self.param::<T>()?.handle()
+    }
+
+    /// Retrieve the row that a typed ID refers to.
+    ///
+    /// Unlike [Self::get], this looks the param up by name, so it finds the
+    /// right param even when several share a paramdef.
+    pub fn resolve<I: ParamId>(&self, id: I) -> Option<&I::Row>
+    where
+        I::Row: ParamDef,
+    {
+        self.res_rep
             .res_cap_holder
-            .entries()
-            .find(|e| e.data.name().as_str().eq(T::NAME))?;
+            .resolve(Some(<I::Row as ParamDef>::INDEX), id)
+    }
+}
 
-        // SAFETY: we shouldn't run into invalid casts because of the code gen dictating T::NAME.
-        unsafe { file_header.data.get(id) }
+impl FD4ParamRepository {
+    /// Adds [rows] to the first param whose paramdef is [T], replacing any
+    /// existing rows with the same IDs.
+    ///
+    /// See [Self::remove_rows] for how the param is rebuilt.
+    pub fn insert_rows<T: ParamDef>(
+        &mut self,
+        rows: impl IntoIterator<Item = (u32, T)>,
+    ) -> Result<(), ParamEditError> {
+        self.rebuild::<T>(|existing| {
+            for (id, row) in rows {
+                existing.insert(id, (0, row.as_bytes().to_vec()));
+            }
+        })
     }
 
-    pub fn get_mut<T: ParamDef>(&mut self, id: u32) -> Option<&mut T> {
-        let file_header = self
+    /// Removes the rows with the given IDs from the first param whose
+    /// paramdef is [T], and returns how many were removed. IDs with no row
+    /// are ignored.
+    ///
+    /// Params are stored in a single allocation, so this and
+    /// [Self::insert_rows] rebuild the param in a new one from the game's
+    /// allocator, with its rows sorted by ID. The old allocation is leaked
+    /// rather than freed, since other game systems may still hold pointers
+    /// into it.
+    pub fn remove_rows<T: ParamDef>(
+        &mut self,
+        ids: impl IntoIterator<Item = u32>,
+    ) -> Result<usize, ParamEditError> {
+        let mut removed = 0;
+        self.rebuild::<T>(|existing| {
+            for id in ids {
+                removed += usize::from(existing.remove(&id).is_some());
+            }
+        })?;
+        Ok(removed)
+    }
+
+    /// Rebuilds the first param whose paramdef is [T] after applying [edit]
+    /// to its rows, which map IDs to name offsets and row data.
+    fn rebuild<T: ParamDef>(
+        &mut self,
+        edit: impl FnOnce(&mut BTreeMap<u32, (usize, Vec<u8>)>),
+    ) -> Result<(), ParamEditError> {
+        let res_cap = self
             .res_rep
             .res_cap_holder
-            .entries_mut()
-            .find(|e| e.data.name().as_str().eq(T::NAME))?;
+            .find_mut::<T>(Some(T::INDEX), None)
+            .ok_or(ParamEditError::NotFound(T::NAME))?;
+        let (data, size) =
+            res_cap
+                .data
+                .rebuild::<T>(res_cap.size as usize, &self.allocator, edit)?;
 
-        // SAFETY: we shouldn't run into invalid casts because of the code gen dictating T::NAME.
-        unsafe { file_header.data.get_mut(id) }
+        // SAFETY: the old allocation is intentionally leaked, since the game
+        // may still point into it.
+        res_cap.data = unsafe { OwnedPtr::from_raw(data) };
+        res_cap.size = size as u64;
+        Ok(())
     }
 }
 
@@ -59,59 +178,37 @@ impl AsMut<FD4ResCap<Self>> for FD4ParamResCap {
     }
 }
 
-#[repr(C)]
-pub struct ParamData {
-    pub header: ParamFileHeader,
-}
+impl ParamResCap for FD4ParamResCap {
+    type Header = ParamFileHeader;
 
-impl ParamData {
-    pub fn name(&self) -> String {
-        unsafe {
-            PCSTR(
-                (self as *const _ as usize + self.header.param_type.name_offset as usize)
-                    as *const _,
-            )
-            .to_string()
-            .unwrap()
-        }
+    fn data(&self) -> &ParamData {
+        &self.data
     }
 
-    fn row_descriptors(&self) -> &[ParamRowDescriptor] {
-        unsafe {
-            // The row descriptors are right after the header.
-            std::slice::from_raw_parts(
-                (self as *const _ as usize + size_of::<ParamFileHeader>()) as *const _,
-                self.header.row_count as usize,
-            )
-        }
+    fn data_mut(&mut self) -> &mut ParamData {
+        &mut self.data
     }
 
-    /// Retrieve a param entry by its ID and type.
-    ///
-    /// # Safety
-    /// Caller has to ensure that the param type passed to T is valid for the param file.
-    pub unsafe fn get<T: Sized>(&self, id: u32) -> Option<&T> {
-        let offset = self
-            .row_descriptors()
+    fn has_name(&self, name: &str) -> bool {
+        self.inner
+            .name
+            .inner
+            .as_bytes()
             .iter()
-            .find(|r| r.id == id)?
-            .data_offset;
-
-        unsafe { Some(&*((self as *const _ as usize + offset) as *const T)) }
+            .copied()
+            .eq(name.encode_utf16())
     }
+}
 
-    /// Retrieve a param entry mutable by its ID and type.
-    ///
-    /// # Safety
-    /// Caller has to ensure that the param type passed to T is valid for the param file.
-    pub unsafe fn get_mut<T: Sized>(&mut self, id: u32) -> Option<&mut T> {
-        let offset = self
-            .row_descriptors()
-            .iter()
-            .find(|r| r.id == id)?
-            .data_offset;
+impl ParamResCapHolder for FD4ResCapHolder<FD4ParamResCap> {
+    type ResCap = FD4ParamResCap;
 
-        unsafe { Some(&mut *((self as *const _ as usize + offset) as *mut T)) }
+    fn res_caps(&self) -> impl Iterator<Item = &FD4ParamResCap> {
+        self.entries()
+    }
+
+    fn res_caps_mut(&mut self) -> impl Iterator<Item = &mut FD4ParamResCap> {
+        self.entries_mut()
     }
 }
 
@@ -127,7 +224,8 @@ pub struct ParamFileHeader {
     flags_2d: u8,
     flags_2e: u8,
     pub paramdef_format_version: u8,
-    unk30: [u8; 0x10],
+    data_offset: u64,
+    unk38: [u8; 0x8],
 }
 
 #[repr(C)]
@@ -138,10 +236,42 @@ pub struct ParamTypeDescriptor {
     _pad8: [u8; 24],
 }
 
-#[repr(C)]
-struct ParamRowDescriptor {
-    id: u32,
-    _pad4: u32,
-    data_offset: usize,
-    name_offset: usize,
+// SAFETY: this is the header of every param the game loads, and the
+// paramdef name is always stored with the param's strings.
+unsafe impl ParamHeader for ParamFileHeader {
+    fn row_count(&self) -> usize {
+        self.row_count as usize
+    }
+
+    fn strings_offset(&self) -> usize {
+        self.strings_offset as usize
+    }
+
+    fn has_wide_row_names(&self) -> bool {
+        self.flags_2e & 0x1 != 0
+    }
+
+    fn paramdef_name(&self) -> &[u8] {
+        // SAFETY: the param type name is a null-terminated string inside the
+        // param file.
+        unsafe {
+            CStr::from_ptr(
+                (self as *const _ as usize + self.param_type.name_offset as usize) as *const _,
+            )
+            .to_bytes()
+        }
+    }
+
+    fn relocate(
+        &mut self,
+        row_count: u16,
+        data_offset: usize,
+        strings_offset: usize,
+        shift: impl Fn(usize) -> usize,
+    ) {
+        self.strings_offset = strings_offset as u32;
+        self.data_offset = data_offset as u64;
+        self.row_count = row_count;
+        self.param_type.name_offset = shift(self.param_type.name_offset as usize) as u32;
+    }
 }
@@ -0,0 +1,330 @@
+use std::collections::BTreeMap;
+
+use crate::param::ParamDef;
+use shared::{ParamFieldChange, ParamFieldInfo, diff_rows};
+
+use super::{FD4ParamRepository, ParamData};
+
+/// A copy of some param rows from [FD4ParamRepository] that can be restored
+/// later, for undoing temporary param changes.
+///
+/// Only the first capture of each row is kept, so capturing a row again
+/// after changing it doesn't overwrite its original state. This makes it
+/// safe to capture rows right before each change without tracking whether
+/// they've been captured already.
+///
+/// Snapshots only cover the contents of rows. Rows inserted or removed with
+/// [FD4ParamRepository::insert_rows] or [FD4ParamRepository::remove_rows]
+/// after capturing aren't removed or restored.
+#[derive(Default)]
+pub struct ParamSnapshot {
+    params: BTreeMap<&'static str, CapturedParam>,
+}
+
+/// The captured rows of a single param.
+struct CapturedParam {
+    fields: &'static [ParamFieldInfo],
+    row_size: usize,
+    param: fn(&FD4ParamRepository) -> Option<&ParamData>,
+    param_mut: fn(&mut FD4ParamRepository) -> Option<&mut ParamData>,
+    rows: BTreeMap<u32, Vec<u8>>,
+}
+
+/// A row whose fields differ from its captured state, returned by
+/// [ParamSnapshot::diff].
+#[derive(Debug, Clone)]
+pub struct ParamRowDiff {
+    /// The name of the row's paramdef, such as `EQUIP_PARAM_WEAPON_ST`.
+    pub paramdef: &'static str,
+
+    /// The row's ID.
+    pub id: u32,
+
+    /// The fields that changed, in the order they're laid out.
+    pub changes: Vec<ParamFieldChange>,
+}
+
+impl ParamSnapshot {
+    /// Creates an empty snapshot.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Captures every row of the first param whose paramdef is [T]. Returns
+    /// false if there's no such param.
+    pub fn capture_param<T: ParamDef>(&mut self, repository: &FD4ParamRepository) -> bool {
+        let Some(data) = repository.param::<T>() else {
+            return false;
+        };
+
+        let captured = self.captured::<T>();
+        for id in data.ids() {
+            captured.capture(data, id);
+        }
+        true
+    }
+
+    /// Captures the row with the given ID from the first param whose
+    /// paramdef is [T]. Returns false if there's no such row.
+    pub fn capture_row<T: ParamDef>(&mut self, repository: &FD4ParamRepository, id: u32) -> bool {
+        let Some(data) = repository.param::<T>() else {
+            return false;
+        };
+        self.captured::<T>().capture(data, id)
+    }
+
+    /// Returns whether any rows have been captured.
+    pub fn is_empty(&self) -> bool {
+        self.params.values().all(|param| param.rows.is_empty())
+    }
+
+    /// Writes every captured row back to the repository, and returns how many
+    /// were restored. Rows that no longer exist are skipped.
+    pub fn restore(&self, repository: &mut FD4ParamRepository) -> usize {
+        let mut restored = 0;
+        for captured in self.params.values() {
+            if let Some(data) = (captured.param_mut)(repository) {
+                restored += captured.restore(data);
+            }
+        }
+        restored
+    }
+
+    /// Lists every captured row whose fields differ from their captured
+    /// values, ordered by paramdef name and then row ID. Rows that no longer
+    /// exist are skipped.
+    pub fn diff(&self, repository: &FD4ParamRepository) -> Vec<ParamRowDiff> {
+        let mut diffs = Vec::new();
+        for (paramdef, captured) in &self.params {
+            if let Some(data) = (captured.param)(repository) {
+                diffs.extend(captured.diff(paramdef, data));
+            }
+        }
+        diffs
+    }
+
+    /// Returns the captured rows for [T], creating an empty entry if needed.
+    fn captured<T: ParamDef>(&mut self) -> &mut CapturedParam {
+        self.params
+            .entry(T::NAME)
+            .or_insert_with(CapturedParam::new::<T>)
+    }
+}
+
+impl CapturedParam {
+    /// Creates an empty capture for the rows of [T].
+    fn new<T: ParamDef>() -> Self {
+        Self {
+            fields: T::FIELDS,
+            row_size: T::ROW_SIZE,
+            param: FD4ParamRepository::param::<T>,
+            param_mut: FD4ParamRepository::param_mut::<T>,
+            rows: BTreeMap::new(),
+        }
+    }
+
+    /// Captures the row with the given ID from [data], unless it's already
+    /// been captured. Returns false if there's no such row.
+    fn capture(&mut self, data: &ParamData, id: u32) -> bool {
+        let Some(bytes) = data.row_bytes(id, self.row_size) else {
+            return false;
+        };
+        self.rows.entry(id).or_insert_with(|| bytes.to_vec());
+        true
+    }
+
+    /// Writes every captured row back to [data], and returns how many were
+    /// restored.
+    fn restore(&self, data: &mut ParamData) -> usize {
+        let mut restored = 0;
+        for (id, bytes) in &self.rows {
+            if let Some(row) = data.row_bytes_mut(*id, self.row_size) {
+                row.copy_from_slice(bytes);
+                restored += 1;
+            }
+        }
+        restored
+    }
+
+    /// Lists every captured row whose fields differ from their values in
+    /// [data].
+    fn diff<'a>(
+        &'a self,
+        paramdef: &'static str,
+        data: &'a ParamData,
+    ) -> impl Iterator<Item = ParamRowDiff> + 'a {
+        self.rows.iter().filter_map(move |(id, old)| {
+            let new = data.row_bytes(*id, self.row_size)?;
+            let changes = diff_rows(self.fields, old, new);
+            (!changes.is_empty()).then_some(ParamRowDiff {
+                paramdef,
+                id: *id,
+                changes,
+            })
+        })
+    }
+}
+
+/// A set of param changes that are undone unless they're committed, created
+/// by [FD4ParamRepository::transaction] or [ParamTransaction::new].
+///
+/// Each row is captured the first time it's borrowed mutably through the
+/// transaction, so only rows that may have changed are restored. Like
+/// [ParamSnapshot], this only covers the contents of existing rows.
+pub struct ParamTransaction<'a> {
+    repository: &'a mut FD4ParamRepository,
+    snapshot: ParamSnapshot,
+}
+
+impl<'a> ParamTransaction<'a> {
+    /// Starts a transaction on [repository]. Its changes are rolled back
+    /// when it's dropped unless [Self::commit] is called first.
+    pub fn new(repository: &'a mut FD4ParamRepository) -> Self {
+        Self {
+            repository,
+            snapshot: ParamSnapshot::new(),
+        }
+    }
+
+    /// Retrieve a row of the first param whose paramdef is [T].
+    pub fn get<T: ParamDef>(&self, id: u32) -> Option<&T> {
+        self.repository.get::<T>(id)
+    }
+
+    /// Retrieve a row of the first param whose paramdef is [T] mutably,
+    /// capturing it first if it hasn't been already.
+    pub fn get_mut<T: ParamDef>(&mut self, id: u32) -> Option<&mut T> {
+        if !self.snapshot.capture_row::<T>(self.repository, id) {
+            return None;
+        }
+        self.repository.get_mut::<T>(id)
+    }
+
+    /// Lists every row changed so far, as in [ParamSnapshot::diff].
+    pub fn diff(&self) -> Vec<ParamRowDiff> {
+        self.snapshot.diff(self.repository)
+    }
+
+    /// Keeps the transaction's changes, and returns a snapshot of the rows
+    /// it changed so they can still be restored later.
+    pub fn commit(mut self) -> ParamSnapshot {
+        std::mem::take(&mut self.snapshot)
+    }
+
+    /// Undoes the transaction's changes, and returns how many rows were
+    /// restored.
+    pub fn rollback(mut self) -> usize {
+        std::mem::take(&mut self.snapshot).restore(self.repository)
+    }
+}
+
+impl Drop for ParamTransaction<'_> {
+    fn drop(&mut self) {
+        self.snapshot.restore(self.repository);
+    }
+}
+
+impl FD4ParamRepository {
+    /// Runs [edit] in a [ParamTransaction], keeping its changes if it
+    /// returns `Ok` and rolling them back if it returns `Err` or panics.
+    pub fn transaction<R, E>(
+        &mut self,
+        edit: impl FnOnce(&mut ParamTransaction<'_>) -> Result<R, E>,
+    ) -> Result<R, E> {
+        let mut transaction = ParamTransaction::new(self);
+        let result = edit(&mut transaction)?;
+        transaction.commit();
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use shared::{ParamFile, ParamFormat};
+
+    use crate::param::SP_EFFECT_PARAM_ST;
+
+    use super::*;
+
+    /// Writes a param file with [rows] using [ParamFile], which lays it out
+    /// the way the game holds it in memory. The buffer is made of `u64`s so
+    /// that it's aligned like the header.
+    fn param_file(rows: &[(u32, SP_EFFECT_PARAM_ST)]) -> Vec<u64> {
+        let mut file = ParamFile::<SP_EFFECT_PARAM_ST>::new(
+            1,
+            ParamFormat::new(
+                ParamFormat::FLAG_01
+                    | ParamFormat::LONG_DATA_OFFSET
+                    | ParamFormat::OFFSET_PARAM_TYPE,
+                ParamFormat::UNICODE_ROW_NAMES,
+                5,
+            ),
+        );
+        file.rows
+            .extend(rows.iter().map(|(id, row)| (*id, None, row.clone())));
+        file.write()
+            .chunks(8)
+            .map(|chunk| {
+                let mut word = [0; 8];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u64::from_le_bytes(word)
+            })
+            .collect()
+    }
+
+    fn as_param(file: &mut [u64]) -> &mut ParamData {
+        // SAFETY: the buffer holds a whole param file and is aligned.
+        unsafe { &mut *file.as_mut_ptr().cast::<ParamData>() }
+    }
+
+    fn sp_effect(icon_id: i32) -> SP_EFFECT_PARAM_ST {
+        let mut row = SP_EFFECT_PARAM_ST::default();
+        row.set_icon_id(icon_id);
+        row
+    }
+
+    #[test]
+    fn restores_first_capture() {
+        let mut file = param_file(&[(10, sp_effect(1)), (20, sp_effect(2))]);
+        let data = as_param(&mut file);
+        let mut captured = CapturedParam::new::<SP_EFFECT_PARAM_ST>();
+
+        assert!(captured.capture(data, 10));
+        // SAFETY: the rows are SP_EFFECT_PARAM_ST.
+        unsafe { data.get_mut::<SP_EFFECT_PARAM_ST>(10) }
+            .unwrap()
+            .set_icon_id(100);
+        // Capturing again doesn't overwrite the original.
+        assert!(captured.capture(data, 10));
+        assert!(!captured.capture(data, 30));
+
+        assert_eq!(captured.restore(data), 1);
+        // SAFETY: the rows are SP_EFFECT_PARAM_ST.
+        assert_eq!(
+            unsafe { data.get::<SP_EFFECT_PARAM_ST>(10) }.map(|row| row.icon_id()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn diffs_changed_fields() {
+        let mut file = param_file(&[(10, sp_effect(1)), (20, sp_effect(2))]);
+        let data = as_param(&mut file);
+        let mut captured = CapturedParam::new::<SP_EFFECT_PARAM_ST>();
+        assert!(captured.capture(data, 10));
+        assert!(captured.capture(data, 20));
+
+        // SAFETY: the rows are SP_EFFECT_PARAM_ST.
+        unsafe { data.get_mut::<SP_EFFECT_PARAM_ST>(20) }
+            .unwrap()
+            .set_icon_id(200);
+
+        let diffs = captured
+            .diff("SP_EFFECT_PARAM_ST", data)
+            .collect::<Vec<_>>();
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].id, 20);
+        assert_eq!(diffs[0].changes.len(), 1);
+        assert_eq!(diffs[0].changes[0].field.rust_name, "icon_id");
+    }
+}
@@ -0,0 +1,47 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use param_generator::Generator;
+
+/// The paramdex meta XML files, which provide enum values and references.
+const META: &str = "paramdex/Meta";
+
+/// The paramdex row names, one `<id> <name>` file per param.
+const NAMES: &str = "paramdex/Names";
+
+fn main() {
+    println!("cargo:rerun-if-changed=paramdex");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    // Row IDs don't get `resolve` methods, since the repository's methods are
+    // unsafe until its layout has been verified.
+    let mut generator = Generator::new("paramdex/Defs").tables_from_features([]);
+
+    // Meta and row names are optional, so the crate still builds before
+    // they have been vendored.
+    if Path::new(META).is_dir() {
+        generator = generator.meta(META);
+    } else {
+        warn_missing(META);
+    }
+    if Path::new(NAMES).is_dir() {
+        generator = generator.names(NAMES);
+    } else {
+        warn_missing(NAMES);
+    }
+
+    generator
+        .write(out_dir.join("param.rs"))
+        .expect("Failed to generate params");
+}
+
+/// Warns that [folder] hasn't been vendored, so the params are generated
+/// without the enums, ID types, or row names it provides.
+fn warn_missing(folder: &str) {
+    println!(
+        "cargo:warning={folder} is missing, so params are generated without it. Run \
+         tools/param-generator/update-paramdex.sh to vendor it."
+    );
+}
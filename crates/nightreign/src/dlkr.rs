@@ -0,0 +1,23 @@
+use std::ptr::NonNull;
+
+use vtable_rs::VPtr;
+
+#[vtable_rs::vtable]
+pub trait DLAllocatorVmt {
+    fn destructor(&mut self, param_2: bool);
+}
+
+#[repr(transparent)]
+pub struct DLAllocatorBase {
+    pub vftable: VPtr<dyn DLAllocatorVmt, Self>,
+}
+
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct DLAllocatorRef(NonNull<DLAllocatorBase>);
+
+impl From<NonNull<DLAllocatorBase>> for DLAllocatorRef {
+    fn from(ptr: NonNull<DLAllocatorBase>) -> Self {
+        Self(ptr)
+    }
+}
@@ -0,0 +1,11 @@
+mod basic_hash_string;
+mod param_repository;
+mod resource;
+mod task;
+mod time;
+
+pub use basic_hash_string::*;
+pub use param_repository::*;
+pub use resource::*;
+pub use task::*;
+pub use time::*;
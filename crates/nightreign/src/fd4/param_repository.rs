@@ -0,0 +1,249 @@
+use std::ffi::CStr;
+
+use crate::param::ParamDef;
+use shared::{OwnedPtr, ParamHeader, ParamId, ParamResCap, ParamResCapHolder};
+
+use super::FD4ResRep;
+use super::resource::{FD4ResCap, FD4ResCapHolder};
+
+/// The repository of every param the game has loaded.
+///
+/// # Safety
+///
+/// This struct's layout, along with those of [FD4ResCap] and
+/// [super::FD4BasicHashString] that it's built on, is assumed to match Elden
+/// Ring's, and hasn't been verified against Nightreign's executable.
+/// Until it has, its methods are unsafe, and callers have to ensure that the
+/// game's repository really has this layout before using them.
+#[repr(C)]
+#[shared::singleton("FD4ParamRepository")]
+pub struct FD4ParamRepository {
+    /// Resource repository holding the actual param data.
+    pub res_rep: FD4ResRep<FD4ParamResCap>,
+}
+
+/// A param file as the game holds it in memory.
+pub type ParamData = shared::ParamData<ParamFileHeader>;
+
+/// A param whose paramdef and row size have already been checked against
+/// [T], returned by [FD4ParamRepository::handle].
+pub type ParamHandle<'a, T> = shared::ParamHandle<'a, ParamFileHeader, T>;
+
+impl FD4ParamRepository {
+    /// Retrieve a row of the first param whose paramdef is [T].
+    ///
+    /// Returns `None` if the live param's rows aren't [ParamDef::ROW_SIZE]
+    /// bytes, since that means [T] doesn't match the game's paramdef.
+    ///
+    /// # Safety
+    /// The repository's layout has to match the game's. See
+    /// [FD4ParamRepository].
+    pub unsafe fn get<T: ParamDef>(&self, id: u32) -> Option<&T> {
+        unsafe { self.handle::<T>() }?.get(id)
+    }
+
+    /// Retrieve a row of the first param whose paramdef is [T] mutably.
+    ///
+    /// Returns `None` if the live param's rows aren't [ParamDef::ROW_SIZE]
+    /// bytes, since that means [T] doesn't match the game's paramdef.
+    ///
+    /// # Safety
+    /// The repository's layout has to match the game's. See
+    /// [FD4ParamRepository].
+    pub unsafe fn get_mut<T: ParamDef>(&mut self, id: u32) -> Option<&mut T> {
+        self.res_rep.res_cap_holder.get_mut::<T>(None, id)
+    }
+
+    /// Iterates over every row of the first param whose paramdef is [T],
+    /// yielding each row's ID, name, and data in ID order.
+    ///
+    /// Yields nothing if there's no such param or its rows aren't
+    /// [ParamDef::ROW_SIZE] bytes.
+    ///
+    /// # Safety
+    /// The repository's layout has to match the game's. See
+    /// [FD4ParamRepository].
+    pub unsafe fn rows<'a, T: ParamDef + 'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (u32, Option<String>, &'a T)> {
+        unsafe { self.handle::<T>() }
+            .into_iter()
+            .flat_map(|handle| handle.rows())
+    }
+
+    /// Like [Self::rows], but yields mutable rows.
+    ///
+    /// # Safety
+    /// The repository's layout has to match the game's. See
+    /// [FD4ParamRepository].
+    pub unsafe fn rows_mut<'a, T: ParamDef + 'a>(
+        &'a mut self,
+    ) -> impl Iterator<Item = (u32, Option<String>, &'a mut T)> {
+        self.res_rep.res_cap_holder.rows_mut::<T>(None)
+    }
+
+    /// Retrieve the first param whose paramdef is [T], if its rows are
+    /// [ParamDef::ROW_SIZE] bytes.
+    ///
+    /// # Safety
+    /// The repository's layout has to match the game's. See
+    /// [FD4ParamRepository].
+    pub unsafe fn param<T: ParamDef>(&self) -> Option<&ParamData> {
+        self.res_rep.res_cap_holder.param::<T>(None)
+    }
+
+    /// Like [Self::param], but mutable.
+    ///
+    /// # Safety
+    /// The repository's layout has to match the game's. See
+    /// [FD4ParamRepository].
+    pub unsafe fn param_mut<T: ParamDef>(&mut self) -> Option<&mut ParamData> {
+        self.res_rep.res_cap_holder.param_mut::<T>(None)
+    }
+
+    /// Finds the first param whose paramdef is [T] and returns a handle to
+    /// its rows.
+    ///
+    /// Each lookup finds the param and checks it against [T] again, so code
+    /// that reads the same param repeatedly, such as every frame, can save
+    /// that by holding onto a handle.
+    ///
+    /// # Safety
+    /// The repository's layout has to match the game's. See
+    /// [FD4ParamRepository].
+    pub unsafe fn handle<T: ParamDef>(&self) -> Option<ParamHandle<'_, T>> {
+        unsafe { self.param::<T>() }?.handle()
+    }
+
+    /// Retrieve the row that a typed ID refers to.
+    ///
+    /// Unlike [Self::get], this looks the param up by name, so it finds the
+    /// right param even when several share a paramdef.
+    ///
+    /// # Safety
+    /// The repository's layout has to match the game's. See
+    /// [FD4ParamRepository].
+    pub unsafe fn resolve<I: ParamId>(&self, id: I) -> Option<&I::Row>
+    where
+        I::Row: ParamDef,
+    {
+        self.res_rep.res_cap_holder.resolve(None, id)
+    }
+}
+
+#[repr(C)]
+pub struct FD4ParamResCap {
+    pub inner: FD4ResCap<Self>,
+    /// Size of data at pointer.
+    pub size: u64,
+    /// Raw row data for this param file.
+    pub data: OwnedPtr<ParamData>,
+}
+
+impl AsRef<FD4ResCap<Self>> for FD4ParamResCap {
+    fn as_ref(&self) -> &FD4ResCap<Self> {
+        &self.inner
+    }
+}
+
+impl AsMut<FD4ResCap<Self>> for FD4ParamResCap {
+    fn as_mut(&mut self) -> &mut FD4ResCap<Self> {
+        &mut self.inner
+    }
+}
+
+impl ParamResCap for FD4ParamResCap {
+    type Header = ParamFileHeader;
+
+    fn data(&self) -> &ParamData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ParamData {
+        &mut self.data
+    }
+
+    fn has_name(&self, name: &str) -> bool {
+        self.inner
+            .name
+            .inner
+            .to_str()
+            .is_ok_and(|res_name| res_name == name)
+    }
+}
+
+impl ParamResCapHolder for FD4ResCapHolder<FD4ParamResCap> {
+    type ResCap = FD4ParamResCap;
+
+    fn res_caps(&self) -> impl Iterator<Item = &FD4ParamResCap> {
+        self.entries()
+    }
+
+    fn res_caps_mut(&mut self) -> impl Iterator<Item = &mut FD4ParamResCap> {
+        self.entries_mut()
+    }
+}
+
+#[repr(C)]
+pub struct ParamFileHeader {
+    strings_offset: u32,
+    short_data_offset: u16,
+    unk6: u16,
+    pub paramdef_version: u16,
+    pub row_count: u16,
+    param_type: ParamTypeDescriptor,
+    endianness: u8,
+    flags_2d: u8,
+    flags_2e: u8,
+    pub paramdef_format_version: u8,
+    data_offset: u64,
+    unk38: [u8; 0x8],
+}
+
+#[repr(C)]
+pub struct ParamTypeDescriptor {
+    unk0: u32,
+    name_offset: u32,
+    /// String will be stored here if it fits.
+    _pad8: [u8; 24],
+}
+
+// SAFETY: this is the header of every param the game loads, and the
+// paramdef name is always stored with the param's strings.
+unsafe impl ParamHeader for ParamFileHeader {
+    fn row_count(&self) -> usize {
+        self.row_count as usize
+    }
+
+    fn strings_offset(&self) -> usize {
+        self.strings_offset as usize
+    }
+
+    fn has_wide_row_names(&self) -> bool {
+        self.flags_2e & 0x1 != 0
+    }
+
+    fn paramdef_name(&self) -> &[u8] {
+        // SAFETY: the param type name is a null-terminated string inside the
+        // param file.
+        unsafe {
+            CStr::from_ptr(
+                (self as *const _ as usize + self.param_type.name_offset as usize) as *const _,
+            )
+            .to_bytes()
+        }
+    }
+
+    fn relocate(
+        &mut self,
+        row_count: u16,
+        data_offset: usize,
+        strings_offset: usize,
+        shift: impl Fn(usize) -> usize,
+    ) {
+        self.strings_offset = strings_offset as u32;
+        self.data_offset = data_offset as u64;
+        self.row_count = row_count;
+        self.param_type.name_offset = shift(self.param_type.name_offset as usize) as u32;
+    }
+}
@@ -1 +1,7 @@
+extern crate fromsoftware_shared as shared;
+
+pub mod cs;
+pub mod dlkr;
+pub mod dltx;
+pub mod fd4;
 pub mod param;
@@ -2,6 +2,7 @@ pub mod arxan;
 pub mod dl_math;
 pub mod ext;
 pub mod owned_pointer;
+pub mod param;
 pub mod program;
 pub mod rtti;
 mod r#static;
@@ -10,6 +11,7 @@ pub mod task;
 pub use arxan::*;
 pub use dl_math::*;
 pub use owned_pointer::*;
+pub use param::*;
 pub use program::*;
 pub use rtti::*;
 pub use r#static::*;
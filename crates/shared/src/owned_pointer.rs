@@ -16,6 +16,16 @@ use std::{
 pub struct OwnedPtr<T>(NonNull<T>);
 
 impl<T> OwnedPtr<T> {
+    /// Wraps a pointer to memory that the containing structure will own, such
+    /// as an allocation from the game's allocator.
+    ///
+    /// # Safety
+    ///
+    /// [ptr] must point to a valid `T` that nothing else owns.
+    pub unsafe fn from_raw(ptr: NonNull<T>) -> Self {
+        Self(ptr)
+    }
+
     pub fn as_ptr(&self) -> *mut T {
         self.0.as_ptr()
     }
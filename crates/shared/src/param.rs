@@ -0,0 +1,31 @@
+//! Game-agnostic types for working with params. The param structs themselves
+//! are generated separately for each game, but they all describe their layouts
+//! using the types in this module.
+
+mod data;
+mod diff;
+mod field;
+mod file;
+mod fixstr;
+mod id;
+mod layout;
+#[cfg(feature = "regulation")]
+mod regulation;
+mod repository;
+#[cfg(feature = "serde")]
+mod row_serde;
+mod version;
+
+pub use data::*;
+pub use diff::*;
+pub use field::*;
+pub use file::*;
+pub use fixstr::*;
+pub use id::*;
+pub use layout::*;
+#[cfg(feature = "regulation")]
+pub use regulation::*;
+pub use repository::*;
+#[cfg(feature = "serde")]
+pub use row_serde::*;
+pub use version::*;
@@ -0,0 +1,641 @@
+use std::alloc::{GlobalAlloc, Layout};
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use encoding_rs::SHIFT_JIS;
+use thiserror::Error;
+
+use super::ParamLayout;
+
+/// The header at the start of a param file as a game holds it in memory.
+///
+/// Each game's `FD4ParamRepository` stores its params in the same 64-bit
+/// layout, but games differ in how they find the paramdef name, so each game
+/// crate implements this for its own header.
+///
+/// # Safety
+///
+/// Implementors must match the game's header exactly, including its size,
+/// since the row descriptors start right after it. [Self::paramdef_name]
+/// may read past the end of the header, but only within the param file
+/// it's at the start of.
+pub unsafe trait ParamHeader: Sized {
+    /// The number of rows in the param.
+    fn row_count(&self) -> usize;
+
+    /// The offset of the param's strings, which follow its row data.
+    fn strings_offset(&self) -> usize;
+
+    /// Whether row names are UTF-16 rather than Shift-JIS.
+    fn has_wide_row_names(&self) -> bool;
+
+    /// The name of the param's paramdef, without a null terminator.
+    fn paramdef_name(&self) -> &[u8];
+
+    /// Updates the header after its param is rebuilt with [row_count] rows,
+    /// whose data starts at [data_offset] and whose strings start at
+    /// [strings_offset]. Offsets into the old strings can be moved to their
+    /// new location with [shift].
+    fn relocate(
+        &mut self,
+        row_count: u16,
+        data_offset: usize,
+        strings_offset: usize,
+        shift: impl Fn(usize) -> usize,
+    );
+}
+
+/// An error returned when adding or removing rows in a live param.
+#[derive(Debug, Error)]
+pub enum ParamEditError {
+    /// The repository has no param with the given paramdef.
+    #[error("No param uses paramdef {0}")]
+    NotFound(&'static str),
+
+    /// The live param's rows are a different size than the paramdef's, so
+    /// the struct doesn't match the game's paramdef.
+    #[error("{0} rows are a different size than the game's")]
+    RowSizeMismatch(&'static str),
+
+    /// The edited param would have more rows than its header can count.
+    #[error("Params can't have more than {max} rows, but this has {0}", max = u16::MAX)]
+    TooManyRows(usize),
+
+    /// The game allocator couldn't allocate the rebuilt param.
+    #[error("Couldn't allocate {0} bytes for the param")]
+    AllocationFailed(usize),
+}
+
+/// A param file as a game holds it in memory, whose header is [H].
+///
+/// This is the in-memory counterpart of [ParamFile](crate::ParamFile). It's
+/// only ever accessed by reference, since its rows and strings follow the
+/// header in the same allocation.
+#[repr(C)]
+pub struct ParamData<H: ParamHeader> {
+    pub header: H,
+}
+
+impl<H: ParamHeader> ParamData<H> {
+    pub fn name(&self) -> String {
+        String::from_utf8_lossy(self.header.paramdef_name()).into_owned()
+    }
+
+    /// Whether this param's paramdef is [T]. Unlike comparing [Self::name],
+    /// this doesn't allocate.
+    pub fn has_paramdef<T: ParamLayout>(&self) -> bool {
+        self.header.paramdef_name() == T::NAME.as_bytes()
+    }
+
+    fn row_descriptors(&self) -> &[ParamRowDescriptor] {
+        unsafe {
+            // The row descriptors are right after the header.
+            std::slice::from_raw_parts(
+                (self as *const _ as usize + size_of::<H>()) as *const _,
+                self.header.row_count(),
+            )
+        }
+    }
+
+    /// The size in bytes of each row, determined from the distance between the
+    /// first two rows. Returns `None` if there are fewer than two rows.
+    pub fn row_size(&self) -> Option<usize> {
+        match self.row_descriptors() {
+            [first, second, ..] => second.data_offset.checked_sub(first.data_offset),
+            _ => None,
+        }
+    }
+
+    /// Returns `self` if its paramdef is [T] and its rows are laid out as [T]
+    /// expects.
+    ///
+    /// Params with a single row can't be measured, so this only checks that
+    /// the row ends before the param's strings.
+    pub fn checked<T: ParamLayout>(&self) -> Option<&Self> {
+        if !self.has_paramdef::<T>() {
+            return None;
+        }
+
+        let fits = match self.row_size() {
+            Some(size) => size == size_of::<T>(),
+            None => self
+                .row_descriptors()
+                .iter()
+                .all(|row| row.data_offset + size_of::<T>() <= self.header.strings_offset()),
+        };
+        fits.then_some(self)
+    }
+
+    /// Like [Self::checked], but mutable.
+    pub fn checked_mut<T: ParamLayout>(&mut self) -> Option<&mut Self> {
+        self.checked::<T>()?;
+        Some(self)
+    }
+
+    /// Returns a handle to this param's rows if its paramdef is [T] and its
+    /// rows are laid out as [T] expects. See [Self::checked].
+    pub fn handle<T: ParamLayout>(&self) -> Option<ParamHandle<'_, H, T>> {
+        Some(ParamHandle {
+            data: self.checked::<T>()?,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns [len] bytes of this param starting at [offset].
+    fn bytes(&self, offset: usize, len: usize) -> &[u8] {
+        // SAFETY: callers only read regions inside the param file.
+        unsafe { std::slice::from_raw_parts((self as *const _ as *const u8).add(offset), len) }
+    }
+
+    /// Returns the [len] bytes of the row with the given ID.
+    ///
+    /// [len] isn't checked against the param's row size, so callers should
+    /// only pass the size of a layout the param has been
+    /// [checked](Self::checked) against.
+    pub fn row_bytes(&self, id: u32, len: usize) -> Option<&[u8]> {
+        Some(self.bytes(self.row_offset(id)?, len))
+    }
+
+    /// Like [Self::row_bytes], but mutable.
+    pub fn row_bytes_mut(&mut self, id: u32, len: usize) -> Option<&mut [u8]> {
+        let offset = self.row_offset(id)?;
+        // SAFETY: rows are inside the param file.
+        Some(unsafe {
+            std::slice::from_raw_parts_mut((self as *mut _ as *mut u8).add(offset), len)
+        })
+    }
+
+    /// The number of rows in this param.
+    pub fn len(&self) -> usize {
+        self.header.row_count()
+    }
+
+    /// Whether this param has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over the IDs of every row in ascending order.
+    pub fn ids(&self) -> impl Iterator<Item = u32> {
+        self.row_descriptors().iter().map(|r| r.id)
+    }
+
+    /// Whether this param has a row with the given ID.
+    pub fn contains(&self, id: u32) -> bool {
+        self.row_offset(id).is_some()
+    }
+
+    /// Returns the name of the row with the given ID, if it exists and has a
+    /// name. Most params loaded by the game have no row names.
+    pub fn row_name(&self, id: u32) -> Option<String> {
+        let descriptors = self.row_descriptors();
+        let index = descriptors.binary_search_by_key(&id, |r| r.id).ok()?;
+        self.name_at(descriptors[index].name_offset)
+    }
+
+    /// Decodes the row name at [offset], which is UTF-16 or Shift-JIS
+    /// depending on the header's flags. Returns `None` for empty names.
+    fn name_at(&self, offset: usize) -> Option<String> {
+        if offset == 0 {
+            return None;
+        }
+
+        let start = self as *const _ as usize + offset;
+        // SAFETY: row names are null-terminated strings inside the param file.
+        let name = unsafe {
+            if self.header.has_wide_row_names() {
+                let start = start as *const u16;
+                let len = (0..).take_while(|&i| *start.add(i) != 0).count();
+                String::from_utf16_lossy(std::slice::from_raw_parts(start, len))
+            } else {
+                let bytes = CStr::from_ptr(start as *const _).to_bytes();
+                SHIFT_JIS.decode_without_bom_handling(bytes).0.into_owned()
+            }
+        };
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// Returns the offset of the row with the given ID. Rows are sorted by
+    /// ID, so this is a binary search.
+    fn row_offset(&self, id: u32) -> Option<usize> {
+        let descriptors = self.row_descriptors();
+        let index = descriptors.binary_search_by_key(&id, |r| r.id).ok()?;
+        Some(descriptors[index].data_offset)
+    }
+
+    /// Retrieve a param entry by its ID and type.
+    ///
+    /// # Safety
+    /// Caller has to ensure that the param type passed to T is valid for the param file.
+    pub unsafe fn get<T: Sized>(&self, id: u32) -> Option<&T> {
+        let offset = self.row_offset(id)?;
+
+        unsafe { Some(&*((self as *const _ as usize + offset) as *const T)) }
+    }
+
+    /// Retrieve a param entry mutable by its ID and type.
+    ///
+    /// # Safety
+    /// Caller has to ensure that the param type passed to T is valid for the param file.
+    pub unsafe fn get_mut<T: Sized>(&mut self, id: u32) -> Option<&mut T> {
+        let offset = self.row_offset(id)?;
+
+        unsafe { Some(&mut *((self as *const _ as usize + offset) as *mut T)) }
+    }
+
+    /// Iterates over every row, yielding each row's ID, name, and data in ID
+    /// order.
+    ///
+    /// # Safety
+    /// Caller has to ensure that the param type passed to T is valid for the param file.
+    pub unsafe fn rows<'a, T: 'a>(&'a self) -> impl Iterator<Item = (u32, Option<String>, &'a T)> {
+        let base = self as *const _ as usize;
+        self.row_descriptors().iter().map(move |r| {
+            let row = unsafe { &*((base + r.data_offset) as *const T) };
+            (r.id, self.name_at(r.name_offset), row)
+        })
+    }
+
+    /// Iterates over every row mutably, yielding each row's ID, name, and
+    /// data in ID order.
+    ///
+    /// # Safety
+    /// Caller has to ensure that the param type passed to T is valid for the param file.
+    pub unsafe fn rows_mut<'a, T: 'a>(
+        &'a mut self,
+    ) -> impl Iterator<Item = (u32, Option<String>, &'a mut T)> {
+        let base = self as *mut _ as usize;
+        let this = &*self;
+        this.row_descriptors().iter().map(move |r| {
+            // Each descriptor points to a distinct row, so the rows never
+            // alias.
+            let row = unsafe { &mut *((base + r.data_offset) as *mut T) };
+            (r.id, this.name_at(r.name_offset), row)
+        })
+    }
+
+    /// Copies this param into a new allocation from [allocator] after
+    /// applying [edit] to its rows, which map IDs to name offsets and row
+    /// data. [size] is the size of this param's allocation.
+    ///
+    /// Params are stored in a single allocation, so adding or removing rows
+    /// means rebuilding them, with their rows sorted by ID. This returns the
+    /// new param and its size, and leaves `self` untouched, since other game
+    /// systems may still hold pointers into it.
+    pub fn rebuild<T: ParamLayout>(
+        &self,
+        size: usize,
+        allocator: &impl GlobalAlloc,
+        edit: impl FnOnce(&mut BTreeMap<u32, (usize, Vec<u8>)>),
+    ) -> Result<(NonNull<Self>, usize), ParamEditError> {
+        if !self.has_paramdef::<T>() {
+            return Err(ParamEditError::NotFound(T::NAME));
+        }
+        let row_size = size_of::<T>();
+        let old = self
+            .checked::<T>()
+            .ok_or(ParamEditError::RowSizeMismatch(T::NAME))?;
+
+        let mut rows = old
+            .row_descriptors()
+            .iter()
+            .map(|r| {
+                let row = old.bytes(r.data_offset, row_size).to_vec();
+                (r.id, (r.name_offset, row))
+            })
+            .collect::<BTreeMap<_, _>>();
+        edit(&mut rows);
+        let row_count =
+            u16::try_from(rows.len()).map_err(|_| ParamEditError::TooManyRows(rows.len()))?;
+
+        // Everything after the row data is strings, which are copied as-is.
+        let old_strings_offset = old.header.strings_offset();
+        let strings = old.bytes(old_strings_offset, size.saturating_sub(old_strings_offset));
+
+        let descriptors_offset = size_of::<H>();
+        let data_offset = (descriptors_offset + rows.len() * size_of::<ParamRowDescriptor>())
+            .next_multiple_of(0x10);
+        let strings_offset = (data_offset + rows.len() * row_size).next_multiple_of(0x10);
+        let size = strings_offset + strings.len();
+        let shift = |offset: usize| {
+            if offset >= old_strings_offset {
+                offset - old_strings_offset + strings_offset
+            } else {
+                offset
+            }
+        };
+
+        // SAFETY: the layout has a non-zero size.
+        let buffer = unsafe { allocator.alloc(Layout::from_size_align(size, 0x10).unwrap()) };
+        let buffer = NonNull::new(buffer).ok_or(ParamEditError::AllocationFailed(size))?;
+        // SAFETY: the buffer was just allocated with this size.
+        let bytes = unsafe { std::slice::from_raw_parts_mut(buffer.as_ptr(), size) };
+        bytes.fill(0);
+        bytes[..descriptors_offset].copy_from_slice(old.bytes(0, descriptors_offset));
+        bytes[strings_offset..].copy_from_slice(strings);
+
+        let mut descriptors = Vec::with_capacity(rows.len());
+        for (i, (id, (name_offset, row))) in rows.into_iter().enumerate() {
+            let row_offset = data_offset + i * row_size;
+            bytes[row_offset..row_offset + row_size].copy_from_slice(&row);
+            descriptors.push(ParamRowDescriptor {
+                id,
+                _pad4: 0,
+                data_offset: row_offset,
+                name_offset: if name_offset == 0 {
+                    0
+                } else {
+                    shift(name_offset)
+                },
+            });
+        }
+
+        // SAFETY: the descriptors fit between the header and the row data,
+        // and the buffer is aligned to 16 bytes.
+        let new = unsafe {
+            std::ptr::copy_nonoverlapping(
+                descriptors.as_ptr(),
+                buffer.as_ptr().add(descriptors_offset).cast(),
+                descriptors.len(),
+            );
+            &mut *buffer.as_ptr().cast::<Self>()
+        };
+        new.header
+            .relocate(row_count, data_offset, strings_offset, shift);
+        Ok((NonNull::from(new), size))
+    }
+}
+
+/// A param whose paramdef and row size have already been checked against
+/// [T], returned by [ParamData::handle].
+pub struct ParamHandle<'a, H: ParamHeader, T: ParamLayout> {
+    data: &'a ParamData<H>,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, H: ParamHeader, T: ParamLayout> ParamHandle<'a, H, T> {
+    /// Retrieve a row by its ID.
+    pub fn get(&self, id: u32) -> Option<&'a T> {
+        // SAFETY: the paramdef name and row size were checked when the handle
+        // was created.
+        unsafe { self.data.get(id) }
+    }
+
+    /// Iterates over every row, yielding each row's ID, name, and data in ID
+    /// order.
+    pub fn rows(&self) -> impl Iterator<Item = (u32, Option<String>, &'a T)> + use<'a, H, T> {
+        // SAFETY: the paramdef name and row size were checked when the handle
+        // was created.
+        unsafe { self.data.rows() }
+    }
+
+    /// The raw param file this handle reads from.
+    pub fn data(&self) -> &'a ParamData<H> {
+        self.data
+    }
+}
+
+impl<H: ParamHeader, T: ParamLayout> Clone for ParamHandle<'_, H, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<H: ParamHeader, T: ParamLayout> Copy for ParamHandle<'_, H, T> {}
+
+#[repr(C)]
+struct ParamRowDescriptor {
+    id: u32,
+    _pad4: u32,
+    data_offset: usize,
+    name_offset: usize,
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::alloc::System;
+
+    use super::*;
+    use crate::{ParamFieldInfo, ParamFile, ParamFormat};
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    #[repr(C)]
+    pub struct TestRow(pub [u8; 8]);
+
+    impl ParamLayout for TestRow {
+        const NAME: &str = "TEST_PARAM_ST";
+        const FIELDS: &[ParamFieldInfo] = &[];
+
+        fn as_bytes(&self) -> &[u8] {
+            &self.0
+        }
+
+        fn as_bytes_mut(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
+    }
+
+    /// The 64-bit header that [ParamFile] writes, which is the same one the
+    /// games hold in memory.
+    #[repr(C)]
+    pub struct TestHeader {
+        strings_offset: u32,
+        _unk4: [u8; 6],
+        row_count: u16,
+        _unkc: u32,
+        name_offset: u32,
+        _unk14: [u8; 0x1a],
+        flags_2e: u8,
+        _unk2f: u8,
+        data_offset: u64,
+        _unk38: [u8; 8],
+    }
+
+    unsafe impl ParamHeader for TestHeader {
+        fn row_count(&self) -> usize {
+            self.row_count as usize
+        }
+
+        fn strings_offset(&self) -> usize {
+            self.strings_offset as usize
+        }
+
+        fn has_wide_row_names(&self) -> bool {
+            self.flags_2e & 0x1 != 0
+        }
+
+        fn paramdef_name(&self) -> &[u8] {
+            // SAFETY: the test files always have a null-terminated name.
+            unsafe {
+                CStr::from_ptr((self as *const _ as usize + self.name_offset as usize) as *const _)
+                    .to_bytes()
+            }
+        }
+
+        fn relocate(
+            &mut self,
+            row_count: u16,
+            data_offset: usize,
+            strings_offset: usize,
+            shift: impl Fn(usize) -> usize,
+        ) {
+            self.row_count = row_count;
+            self.data_offset = data_offset as u64;
+            self.strings_offset = strings_offset as u32;
+            self.name_offset = shift(self.name_offset as usize) as u32;
+        }
+    }
+
+    /// Writes a param with [rows] and returns it in an aligned buffer.
+    pub fn param_file(rows: &[(u32, Option<&str>, u8)]) -> Vec<u64> {
+        let mut file = ParamFile::<TestRow>::new(
+            1,
+            ParamFormat::new(
+                ParamFormat::FLAG_01
+                    | ParamFormat::LONG_DATA_OFFSET
+                    | ParamFormat::OFFSET_PARAM_TYPE,
+                ParamFormat::UNICODE_ROW_NAMES,
+                5,
+            ),
+        );
+        for (id, name, fill) in rows {
+            file.rows
+                .push((*id, name.map(str::to_string), TestRow([*fill; 8])));
+        }
+        let bytes = file.write();
+        let mut buffer = vec![0u64; bytes.len().div_ceil(8)];
+        // SAFETY: the buffer is at least as long as the bytes.
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer.as_mut_ptr().cast(), bytes.len())
+        };
+        buffer
+    }
+
+    pub fn as_param(file: &[u64]) -> &ParamData<TestHeader> {
+        // SAFETY: the buffer holds a param file.
+        unsafe { &*file.as_ptr().cast::<ParamData<TestHeader>>() }
+    }
+
+    pub fn as_param_mut(file: &mut [u64]) -> &mut ParamData<TestHeader> {
+        // SAFETY: the buffer holds a param file.
+        unsafe { &mut *file.as_mut_ptr().cast::<ParamData<TestHeader>>() }
+    }
+
+    #[test]
+    fn reads_rows() {
+        let file = param_file(&[
+            (10, Some("First"), 1),
+            (20, None, 2),
+            (30, Some("三番目"), 3),
+        ]);
+        let param = as_param(&file);
+
+        assert!(param.has_paramdef::<TestRow>());
+        assert_eq!(param.name(), "TEST_PARAM_ST");
+        assert_eq!(param.len(), 3);
+        assert_eq!(param.row_size(), Some(8));
+        assert_eq!(param.ids().collect::<Vec<_>>(), [10, 20, 30]);
+        assert!(param.contains(20) && !param.contains(15));
+        assert_eq!(param.row_name(30).as_deref(), Some("三番目"));
+        assert_eq!(param.row_name(20), None);
+        // SAFETY: the rows are TestRows.
+        assert_eq!(unsafe { param.get::<TestRow>(30) }, Some(&TestRow([3; 8])));
+        assert_eq!(unsafe { param.get::<TestRow>(15) }, None);
+
+        let handle = param.handle::<TestRow>().unwrap();
+        assert_eq!(handle.get(20), Some(&TestRow([2; 8])));
+        assert_eq!(handle.get(25), None);
+        let names = handle.rows().map(|(_, name, _)| name).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [Some("First".to_string()), None, Some("三番目".to_string())]
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_row_size() {
+        #[derive(Default)]
+        #[repr(C)]
+        struct WideRow([u8; 16]);
+
+        impl ParamLayout for WideRow {
+            const NAME: &str = "TEST_PARAM_ST";
+            const FIELDS: &[ParamFieldInfo] = &[];
+
+            fn as_bytes(&self) -> &[u8] {
+                &self.0
+            }
+
+            fn as_bytes_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+
+        let file = param_file(&[(10, None, 1), (20, None, 2)]);
+        assert!(as_param(&file).handle::<WideRow>().is_none());
+    }
+
+    #[test]
+    fn rejects_other_paramdef() {
+        #[derive(Default)]
+        #[repr(C)]
+        struct OtherRow([u8; 8]);
+
+        impl ParamLayout for OtherRow {
+            const NAME: &str = "OTHER_PARAM_ST";
+            const FIELDS: &[ParamFieldInfo] = &[];
+
+            fn as_bytes(&self) -> &[u8] {
+                &self.0
+            }
+
+            fn as_bytes_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+
+        let file = param_file(&[(10, None, 1), (20, None, 2)]);
+        let param = as_param(&file);
+        assert!(param.checked::<OtherRow>().is_none());
+        assert!(param.handle::<OtherRow>().is_none());
+        assert!(matches!(
+            param.rebuild::<OtherRow>(file.len() * 8, &System, |_| {}),
+            Err(ParamEditError::NotFound("OTHER_PARAM_ST"))
+        ));
+    }
+
+    #[test]
+    fn rebuild_keeps_names() {
+        let file = param_file(&[(10, Some("First"), 1), (30, Some("Third"), 3)]);
+        let param = as_param(&file);
+        let size = file.len() * 8;
+
+        let (new, new_size) = param
+            .rebuild::<TestRow>(size, &System, |rows| {
+                rows.remove(&10);
+                rows.insert(20, (0, vec![2; 8]));
+                rows.insert(40, (0, vec![4; 8]));
+            })
+            .unwrap();
+        // SAFETY: rebuild returns a param file.
+        let new = unsafe { new.as_ref() };
+
+        assert!(new.has_paramdef::<TestRow>());
+        assert_eq!(new.ids().collect::<Vec<_>>(), [20, 30, 40]);
+        assert_eq!(new.row_name(30).as_deref(), Some("Third"));
+        assert_eq!(new.row_name(20), None);
+        let handle = new.handle::<TestRow>().unwrap();
+        assert_eq!(handle.get(40), Some(&TestRow([4; 8])));
+        assert_eq!(handle.get(30), Some(&TestRow([3; 8])));
+
+        // SAFETY: the param was allocated by `System` with this layout.
+        unsafe {
+            System.dealloc(
+                (new as *const ParamData<_>).cast_mut().cast(),
+                Layout::from_size_align(new_size, 0x10).unwrap(),
+            )
+        };
+    }
+}
@@ -0,0 +1,100 @@
+use super::{ParamFieldInfo, ParamFieldValue};
+
+/// A field whose value differs between two versions of a row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamFieldChange {
+    /// The field that changed.
+    pub field: &'static ParamFieldInfo,
+
+    /// The field's value in the older row.
+    pub old: ParamFieldValue,
+
+    /// The field's value in the newer row.
+    pub new: ParamFieldValue,
+}
+
+/// Returns the visible fields in [fields] whose values differ between [old]
+/// and [new], the raw bytes of two rows with the same layout.
+///
+/// Fields are compared bit-for-bit, so a float field that's NaN in both rows
+/// isn't reported as changed.
+///
+/// ## Panics
+///
+/// Panics if either row is too short to contain the fields.
+pub fn diff_rows(
+    fields: &'static [ParamFieldInfo],
+    old: &[u8],
+    new: &[u8],
+) -> Vec<ParamFieldChange> {
+    fields
+        .iter()
+        .filter(|field| !field.hidden)
+        .filter(|field| {
+            let range = field.offset..field.offset + field.size();
+            match field.bit_width {
+                Some(_) => field.read(old) != field.read(new),
+                None => old[range.clone()] != new[range],
+            }
+        })
+        .map(|field| ParamFieldChange {
+            field,
+            old: field.read(old),
+            new: field.read(new),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ParamFieldType;
+
+    const FIELD: ParamFieldInfo = ParamFieldInfo {
+        name: "hp",
+        rust_name: "hp",
+        display_name: None,
+        alt_name: None,
+        field_type: ParamFieldType::S16,
+        offset: 0,
+        bit_offset: 0,
+        bit_width: None,
+        array_len: None,
+        minimum: None,
+        maximum: None,
+        hidden: false,
+    };
+
+    const FIELDS: &[ParamFieldInfo] = &[
+        FIELD,
+        ParamFieldInfo {
+            name: "isBoss",
+            rust_name: "is_boss",
+            field_type: ParamFieldType::U8,
+            offset: 2,
+            bit_width: Some(1),
+            ..FIELD
+        },
+        ParamFieldInfo {
+            name: "pad",
+            rust_name: "pad",
+            field_type: ParamFieldType::Dummy8,
+            offset: 3,
+            hidden: true,
+            ..FIELD
+        },
+    ];
+
+    #[test]
+    fn reports_changed_fields() {
+        // The unused bits of the bitfield and the padding change too, but
+        // neither is a visible field.
+        let changes = diff_rows(FIELDS, &[1, 0, 0b0000, 0], &[1, 0, 0b1111, 5]);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field.name, "isBoss");
+        assert_eq!(changes[0].old, ParamFieldValue::U8(0));
+        assert_eq!(changes[0].new, ParamFieldValue::U8(1));
+
+        assert!(diff_rows(FIELDS, &[1, 0, 1, 0], &[1, 0, 1, 0]).is_empty());
+    }
+}
@@ -0,0 +1,529 @@
+use std::fmt;
+
+use thiserror::Error;
+
+/// The primitive type of a param field, as declared in its paramdef.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParamFieldType {
+    U8,
+    S8,
+    U16,
+    S16,
+    U32,
+    S32,
+    F32,
+    /// Unused padding bytes.
+    Dummy8,
+    /// A single byte of a Shift-JIS string. These always appear as arrays.
+    Fixstr,
+    /// A single UTF-16 code unit of a wide string. These always appear as
+    /// arrays.
+    FixstrW,
+}
+
+impl ParamFieldType {
+    /// The size in bytes of a single value of this type.
+    pub const fn size(self) -> usize {
+        match self {
+            Self::U8 | Self::S8 | Self::Dummy8 | Self::Fixstr => 1,
+            Self::U16 | Self::S16 | Self::FixstrW => 2,
+            Self::U32 | Self::S32 | Self::F32 => 4,
+        }
+    }
+
+    /// The name of this type as it's written in paramdef files.
+    pub const fn def_name(self) -> &'static str {
+        match self {
+            Self::U8 => "u8",
+            Self::S8 => "s8",
+            Self::U16 => "u16",
+            Self::S16 => "s16",
+            Self::U32 => "u32",
+            Self::S32 => "s32",
+            Self::F32 => "f32",
+            Self::Dummy8 => "dummy8",
+            Self::Fixstr => "fixstr",
+            Self::FixstrW => "fixstrW",
+        }
+    }
+
+    /// Reads a single value of this type from the start of [bytes].
+    fn read(self, bytes: &[u8]) -> ParamFieldValue {
+        fn array<const N: usize>(bytes: &[u8]) -> [u8; N] {
+            bytes[..N].try_into().unwrap()
+        }
+
+        match self {
+            Self::U8 | Self::Dummy8 | Self::Fixstr => ParamFieldValue::U8(bytes[0]),
+            Self::S8 => ParamFieldValue::S8(i8::from_le_bytes(array(bytes))),
+            Self::U16 | Self::FixstrW => ParamFieldValue::U16(u16::from_le_bytes(array(bytes))),
+            Self::S16 => ParamFieldValue::S16(i16::from_le_bytes(array(bytes))),
+            Self::U32 => ParamFieldValue::U32(u32::from_le_bytes(array(bytes))),
+            Self::S32 => ParamFieldValue::S32(i32::from_le_bytes(array(bytes))),
+            Self::F32 => ParamFieldValue::F32(f32::from_le_bytes(array(bytes))),
+        }
+    }
+
+    /// Writes [value] to the start of [bytes]. Returns `false` without writing
+    /// anything if [value] isn't a value of this type.
+    fn write(self, bytes: &mut [u8], value: &ParamFieldValue) -> bool {
+        use ParamFieldValue as V;
+        let source = match (self, value) {
+            (Self::U8 | Self::Dummy8 | Self::Fixstr, V::U8(v)) => &v.to_le_bytes()[..],
+            (Self::S8, V::S8(v)) => &v.to_le_bytes()[..],
+            (Self::U16 | Self::FixstrW, V::U16(v)) => &v.to_le_bytes()[..],
+            (Self::S16, V::S16(v)) => &v.to_le_bytes()[..],
+            (Self::U32, V::U32(v)) => &v.to_le_bytes()[..],
+            (Self::S32, V::S32(v)) => &v.to_le_bytes()[..],
+            (Self::F32, V::F32(v)) => &v.to_le_bytes()[..],
+            _ => return false,
+        };
+        bytes[..source.len()].copy_from_slice(source);
+        true
+    }
+
+    /// Converts the raw bits of a bitfield into a value of this type.
+    fn value_from_bits(self, bits: u8) -> ParamFieldValue {
+        match self {
+            Self::U8 | Self::Dummy8 | Self::Fixstr | Self::F32 => ParamFieldValue::U8(bits),
+            Self::S8 => ParamFieldValue::S8(bits as i8),
+            Self::U16 | Self::FixstrW => ParamFieldValue::U16(bits.into()),
+            Self::S16 => ParamFieldValue::S16(bits.into()),
+            Self::U32 => ParamFieldValue::U32(bits.into()),
+            Self::S32 => ParamFieldValue::S32(bits.into()),
+        }
+    }
+
+    /// Converts a value of this type into the raw bits of a bitfield. Bits
+    /// beyond the width of the field are discarded by the caller.
+    fn value_to_bits(self, value: &ParamFieldValue) -> Option<u8> {
+        use ParamFieldValue as V;
+        match (self, value) {
+            (Self::U8 | Self::Dummy8 | Self::Fixstr | Self::F32, V::U8(v)) => Some(*v),
+            (Self::S8, V::S8(v)) => Some(*v as u8),
+            (Self::U16 | Self::FixstrW, V::U16(v)) => Some(*v as u8),
+            (Self::S16, V::S16(v)) => Some(*v as u8),
+            (Self::U32, V::U32(v)) => Some(*v as u8),
+            (Self::S32, V::S32(v)) => Some(*v as u8),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParamFieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.def_name())
+    }
+}
+
+/// Static information about a single field of a param row, generated from its
+/// paramdef.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParamFieldInfo {
+    /// The field's name in the paramdef, such as `lotItemCategory01`.
+    pub name: &'static str,
+
+    /// The name of the field's accessors in Rust, such as
+    /// `lot_item_category01`. Hidden fields don't have accessors, but they
+    /// still have a Rust name.
+    pub rust_name: &'static str,
+
+    /// The human-readable name from the paramdef. This is usually Japanese.
+    pub display_name: Option<&'static str>,
+
+    /// The English name from the paramdex meta's `AltName` attribute, such as
+    /// `Weapon Type`. This is only set when the params were generated with
+    /// meta files.
+    pub alt_name: Option<&'static str>,
+
+    /// The primitive type of the field, or of each element if it's an array.
+    pub field_type: ParamFieldType,
+
+    /// The offset in bytes of the field from the start of the row.
+    pub offset: usize,
+
+    /// The offset of the field's lowest bit within the byte at [offset]. This
+    /// is always 0 for fields that aren't bitfields.
+    ///
+    /// [offset]: Self::offset
+    pub bit_offset: u8,
+
+    /// The width in bits of the field, if it's a bitfield.
+    pub bit_width: Option<u8>,
+
+    /// The number of elements in the field, if it's an array.
+    pub array_len: Option<usize>,
+
+    /// The smallest value the game expects for this field.
+    pub minimum: Option<f64>,
+
+    /// The largest value the game expects for this field.
+    pub maximum: Option<f64>,
+
+    /// Whether the field is padding, reserved, or unknown, and so has no
+    /// generated accessors.
+    pub hidden: bool,
+}
+
+impl ParamFieldInfo {
+    /// The number of bytes this field occupies in the row. Bitfields are
+    /// reported as occupying the whole byte they're stored in.
+    pub const fn size(&self) -> usize {
+        match (self.bit_width, self.array_len) {
+            (Some(_), _) => 1,
+            (None, Some(len)) => self.field_type.size() * len,
+            (None, None) => self.field_type.size(),
+        }
+    }
+
+    /// Reads this field's value from [row], the raw bytes of a param row.
+    ///
+    /// Arrays are returned as [ParamFieldValue::Array]s. Bitfields are
+    /// returned as values of their declared type.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if [row] is too short to contain this field.
+    pub fn read(&self, row: &[u8]) -> ParamFieldValue {
+        let bytes = &row[self.offset..self.offset + self.size()];
+        if let Some(width) = self.bit_width {
+            return self
+                .field_type
+                .value_from_bits((bytes[0] >> self.bit_offset) & bit_mask(width));
+        }
+
+        match self.array_len {
+            Some(_) => ParamFieldValue::Array(
+                bytes
+                    .chunks_exact(self.field_type.size())
+                    .map(|element| self.field_type.read(element))
+                    .collect(),
+            ),
+            None => self.field_type.read(bytes),
+        }
+    }
+
+    /// Writes [value] to this field in [row], the raw bytes of a param row.
+    ///
+    /// Like the generated setters, values that are too wide for a bitfield
+    /// have their upper bits discarded. Nothing is written if this returns an
+    /// error.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if [row] is too short to contain this field.
+    pub fn write(&self, row: &mut [u8], value: &ParamFieldValue) -> Result<(), ParamFieldError> {
+        let size = self.size();
+        let bytes = &mut row[self.offset..self.offset + size];
+        let mismatch = || ParamFieldError::TypeMismatch {
+            field: self.name,
+            expected: self.field_type,
+            actual: value.clone(),
+        };
+
+        if let Some(width) = self.bit_width {
+            let mask = bit_mask(width) << self.bit_offset;
+            let bits = self.field_type.value_to_bits(value).ok_or_else(mismatch)?;
+            bytes[0] = (bytes[0] & !mask) | ((bits << self.bit_offset) & mask);
+            return Ok(());
+        }
+
+        match (self.array_len, value) {
+            (Some(len), ParamFieldValue::Array(elements)) => {
+                if elements.len() != len {
+                    return Err(ParamFieldError::LengthMismatch {
+                        field: self.name,
+                        expected: len,
+                        actual: elements.len(),
+                    });
+                }
+
+                // Check every element before writing so that a bad element
+                // doesn't leave the array half-written.
+                let mut scratch = [0; 4];
+                if !elements
+                    .iter()
+                    .all(|element| self.field_type.write(&mut scratch, element))
+                {
+                    return Err(mismatch());
+                }
+
+                for (element, dest) in elements
+                    .iter()
+                    .zip(bytes.chunks_exact_mut(self.field_type.size()))
+                {
+                    self.field_type.write(dest, element);
+                }
+                Ok(())
+            }
+            (None, _) if self.field_type.write(bytes, value) => Ok(()),
+            _ => Err(mismatch()),
+        }
+    }
+
+    /// Returns an error if [value], or any element of it if it's an array, is
+    /// outside the range given by this field's [minimum] and [maximum].
+    ///
+    /// NaN is never considered in range for fields that have a range at all.
+    ///
+    /// [minimum]: Self::minimum
+    /// [maximum]: Self::maximum
+    pub fn check(&self, value: &ParamFieldValue) -> Result<(), ParamValidationError> {
+        if self.minimum.is_none() && self.maximum.is_none() {
+            return Ok(());
+        }
+
+        if let ParamFieldValue::Array(elements) = value {
+            return elements.iter().try_for_each(|element| self.check(element));
+        }
+
+        let Some(number) = value.as_f64() else {
+            return Ok(());
+        };
+        if self.minimum.is_none_or(|minimum| number >= minimum)
+            && self.maximum.is_none_or(|maximum| number <= maximum)
+            && !number.is_nan()
+        {
+            Ok(())
+        } else {
+            Err(ParamValidationError {
+                field: self.name,
+                value: value.clone(),
+                minimum: self.minimum,
+                maximum: self.maximum,
+            })
+        }
+    }
+}
+
+fn bit_mask(width: u8) -> u8 {
+    ((1u16 << width) - 1) as u8
+}
+
+/// A dynamically-typed value of a param field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamFieldValue {
+    U8(u8),
+    S8(i8),
+    U16(u16),
+    S16(i16),
+    U32(u32),
+    S32(i32),
+    F32(f32),
+    Array(Vec<ParamFieldValue>),
+}
+
+impl ParamFieldValue {
+    /// Returns this value as an [f64], or `None` if it's an array. Every
+    /// scalar param value can be represented exactly as an [f64].
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Self::U8(v) => Some(v.into()),
+            Self::S8(v) => Some(v.into()),
+            Self::U16(v) => Some(v.into()),
+            Self::S16(v) => Some(v.into()),
+            Self::U32(v) => Some(v.into()),
+            Self::S32(v) => Some(v.into()),
+            Self::F32(v) => Some(v.into()),
+            Self::Array(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for ParamFieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::U8(v) => v.fmt(f),
+            Self::S8(v) => v.fmt(f),
+            Self::U16(v) => v.fmt(f),
+            Self::S16(v) => v.fmt(f),
+            Self::U32(v) => v.fmt(f),
+            Self::S32(v) => v.fmt(f),
+            Self::F32(v) => v.fmt(f),
+            Self::Array(elements) => {
+                f.write_str("[")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    element.fmt(f)?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($($ty:ty => $variant:ident),*) => {
+        $(
+            impl From<$ty> for ParamFieldValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_primitive!(u8 => U8, i8 => S8, u16 => U16, i16 => S16, u32 => U32, i32 => S32, f32 => F32);
+
+/// An error returned when accessing a param field dynamically.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ParamFieldError {
+    /// The param has no field with the given name.
+    #[error("No field named {0}")]
+    UnknownField(String),
+
+    /// The value's type doesn't match the field's type.
+    #[error("Field {field} has type {expected}, but got {actual:?}")]
+    TypeMismatch {
+        field: &'static str,
+        expected: ParamFieldType,
+        actual: ParamFieldValue,
+    },
+
+    /// An array value has the wrong number of elements for the field.
+    #[error("Field {field} has {expected} elements, but got {actual}")]
+    LengthMismatch {
+        field: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+/// An error indicating that a param field has a value outside the range its
+/// paramdef allows. The game often crashes or misbehaves when it loads such
+/// values.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("Field {field} is {value}, which is outside of {}", format_range(*.minimum, *.maximum))]
+pub struct ParamValidationError {
+    /// The paramdef name of the invalid field.
+    pub field: &'static str,
+
+    /// The invalid value. For arrays, this is the first invalid element.
+    pub value: ParamFieldValue,
+
+    /// The smallest value allowed for the field.
+    pub minimum: Option<f64>,
+
+    /// The largest value allowed for the field.
+    pub maximum: Option<f64>,
+}
+
+fn format_range(minimum: Option<f64>, maximum: Option<f64>) -> String {
+    match (minimum, maximum) {
+        (Some(minimum), Some(maximum)) => format!("{minimum}..={maximum}"),
+        (Some(minimum), None) => format!("{minimum}.."),
+        (None, Some(maximum)) => format!("..={maximum}"),
+        (None, None) => "..".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FIELD: ParamFieldInfo = ParamFieldInfo {
+        name: "field",
+        rust_name: "field",
+        display_name: None,
+        alt_name: None,
+        field_type: ParamFieldType::S16,
+        offset: 2,
+        bit_offset: 0,
+        bit_width: None,
+        array_len: None,
+        minimum: None,
+        maximum: None,
+        hidden: false,
+    };
+
+    #[test]
+    fn read_and_write_scalar() {
+        let mut row = [0; 4];
+        FIELD.write(&mut row, &ParamFieldValue::S16(-2)).unwrap();
+        assert_eq!(row, [0, 0, 0xfe, 0xff]);
+        assert_eq!(FIELD.read(&row), ParamFieldValue::S16(-2));
+    }
+
+    #[test]
+    fn read_and_write_bitfield() {
+        let field = ParamFieldInfo {
+            field_type: ParamFieldType::U8,
+            offset: 1,
+            bit_offset: 2,
+            bit_width: Some(3),
+            ..FIELD
+        };
+
+        let mut row = [0xff, 0b1000_0011];
+        field.write(&mut row, &ParamFieldValue::U8(0b1101)).unwrap();
+        assert_eq!(row, [0xff, 0b1001_0111]);
+        assert_eq!(field.read(&row), ParamFieldValue::U8(0b101));
+    }
+
+    #[test]
+    fn read_and_write_array() {
+        let field = ParamFieldInfo {
+            field_type: ParamFieldType::U8,
+            offset: 1,
+            array_len: Some(2),
+            ..FIELD
+        };
+
+        let mut row = [0; 3];
+        let value = ParamFieldValue::Array(vec![1u8.into(), 2u8.into()]);
+        field.write(&mut row, &value).unwrap();
+        assert_eq!(row, [0, 1, 2]);
+        assert_eq!(field.read(&row), value);
+    }
+
+    #[test]
+    fn write_rejects_mismatches() {
+        let mut row = [0; 4];
+        assert!(matches!(
+            FIELD.write(&mut row, &ParamFieldValue::U16(1)),
+            Err(ParamFieldError::TypeMismatch { .. })
+        ));
+
+        let field = ParamFieldInfo {
+            array_len: Some(1),
+            ..FIELD
+        };
+        assert!(matches!(
+            field.write(
+                &mut row,
+                &ParamFieldValue::Array(vec![1i16.into(), 2i16.into()])
+            ),
+            Err(ParamFieldError::LengthMismatch { .. })
+        ));
+        assert!(matches!(
+            field.write(&mut row, &ParamFieldValue::Array(vec![1u8.into()])),
+            Err(ParamFieldError::TypeMismatch { .. })
+        ));
+        assert_eq!(row, [0; 4]);
+    }
+
+    #[test]
+    fn check_range() {
+        let field = ParamFieldInfo {
+            minimum: Some(-1.0),
+            maximum: Some(10.0),
+            ..FIELD
+        };
+
+        assert!(field.check(&ParamFieldValue::S16(-1)).is_ok());
+        assert!(field.check(&ParamFieldValue::S16(10)).is_ok());
+        assert!(field.check(&ParamFieldValue::S16(11)).is_err());
+        assert!(field.check(&ParamFieldValue::F32(f32::NAN)).is_err());
+        assert_eq!(
+            field
+                .check(&ParamFieldValue::Array(vec![0i16.into(), (-2i16).into()]))
+                .unwrap_err()
+                .value,
+            ParamFieldValue::S16(-2)
+        );
+        assert!(FIELD.check(&ParamFieldValue::S16(i16::MAX)).is_ok());
+    }
+}
@@ -0,0 +1,754 @@
+use encoding_rs::SHIFT_JIS;
+use thiserror::Error;
+
+use super::ParamLayout;
+
+/// The size of the inline param type name in headers without
+/// [ParamFormat::OFFSET_PARAM_TYPE].
+const INLINE_PARAM_TYPE_SIZE: usize = 0x20;
+
+/// An error returned when reading a param file.
+#[derive(Error, Debug)]
+pub enum ParamFileError {
+    /// The file refers to data beyond its end.
+    #[error("Param file is truncated: expected data at {0:#x}")]
+    Truncated(usize),
+
+    /// The file is big-endian. Only little-endian params from PC releases are
+    /// supported.
+    #[error("Big-endian param files aren't supported")]
+    BigEndian,
+
+    /// The file contains a different param than the one being read.
+    #[error("Expected param type {expected} but found {actual}")]
+    ParamTypeMismatch {
+        expected: &'static str,
+        actual: String,
+    },
+
+    /// The file's rows are a different size than the type being read.
+    #[error("Expected rows of {expected} bytes but found {actual}")]
+    RowSizeMismatch { expected: usize, actual: usize },
+
+    /// A string in the file couldn't be decoded.
+    #[error("Invalid string at {0:#x}")]
+    InvalidString(usize),
+}
+
+/// A [Result] whose error type is [ParamFileError].
+pub type ParamFileResult<T> = Result<T, ParamFileError>;
+
+/// The format details of a param file that don't affect its contents, but do
+/// affect how it's laid out on disk.
+///
+/// Reading a file records these so that writing it back produces identical
+/// bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamFormat {
+    /// The first set of format flags, at offset `0x2D` of the header.
+    pub flags1: u8,
+
+    /// The second set of format flags, at offset `0x2E` of the header.
+    pub flags2: u8,
+
+    /// The version of the paramdef format, at offset `0x2F` of the header.
+    pub paramdef_format_version: u8,
+
+    /// An unknown header field at offset `0x6`.
+    pub unk06: u16,
+
+    /// The original bytes of the param type name for headers that store it
+    /// inline, which may be padded with spaces rather than nulls.
+    inline_param_type: Option<[u8; INLINE_PARAM_TYPE_SIZE]>,
+
+    /// The number of zero bytes between the row descriptors and the row data.
+    descriptor_padding: usize,
+
+    /// The number of zero bytes between the row data and the string table.
+    data_padding: usize,
+
+    /// The number of zero bytes between the param type name and the first row
+    /// name.
+    names_padding: usize,
+
+    /// The number of zero bytes after the last string.
+    trailing_padding: usize,
+}
+
+impl ParamFormat {
+    /// A [flags1](Self::flags1) bit that's always set alongside
+    /// [INT_DATA_OFFSET](Self::INT_DATA_OFFSET) in 32-bit files.
+    pub const FLAG_01: u8 = 0x01;
+
+    /// A [flags1](Self::flags1) bit indicating that the header stores the row
+    /// data offset as a 32-bit integer.
+    pub const INT_DATA_OFFSET: u8 = 0x02;
+
+    /// A [flags1](Self::flags1) bit indicating that the header and row
+    /// descriptors use 64-bit offsets.
+    pub const LONG_DATA_OFFSET: u8 = 0x04;
+
+    /// A [flags1](Self::flags1) bit indicating that the param type name is
+    /// stored in the string table rather than inline in the header.
+    pub const OFFSET_PARAM_TYPE: u8 = 0x80;
+
+    /// A [flags2](Self::flags2) bit indicating that row names are UTF-16
+    /// rather than Shift-JIS.
+    pub const UNICODE_ROW_NAMES: u8 = 0x01;
+
+    /// Creates a format with the given header fields and no extra padding.
+    pub fn new(flags1: u8, flags2: u8, paramdef_format_version: u8) -> Self {
+        Self {
+            flags1,
+            flags2,
+            paramdef_format_version,
+            unk06: 0,
+            inline_param_type: None,
+            descriptor_padding: 0,
+            data_padding: 0,
+            names_padding: 0,
+            trailing_padding: 0,
+        }
+    }
+
+    /// Whether this format uses 64-bit offsets.
+    pub fn is_long(&self) -> bool {
+        self.flags1 & Self::LONG_DATA_OFFSET != 0
+    }
+
+    /// Whether the header stores the data offset as a 32-bit integer after the
+    /// format flags, rather than as a 16-bit integer at offset `0x4`.
+    fn has_int_data_offset(&self) -> bool {
+        self.flags1 & Self::FLAG_01 != 0 && self.flags1 & Self::INT_DATA_OFFSET != 0
+    }
+
+    /// Whether the param type name is stored in the string table.
+    fn has_offset_param_type(&self) -> bool {
+        self.flags1 & Self::OFFSET_PARAM_TYPE != 0
+    }
+
+    /// Whether row names are encoded as UTF-16.
+    fn has_unicode_row_names(&self) -> bool {
+        self.flags2 & Self::UNICODE_ROW_NAMES != 0
+    }
+
+    /// The size of the file header.
+    fn header_size(&self) -> usize {
+        if self.is_long() || self.has_int_data_offset() {
+            0x40
+        } else {
+            0x30
+        }
+    }
+
+    /// The size of each row descriptor.
+    fn descriptor_size(&self) -> usize {
+        if self.is_long() { 0x18 } else { 0xC }
+    }
+
+    /// Returns the inline header bytes for [param_type]. This reuses the
+    /// original bytes if the name hasn't changed, since the padding after the
+    /// name varies between files.
+    fn inline_param_type(&self, param_type: &str) -> [u8; INLINE_PARAM_TYPE_SIZE] {
+        if let Some(original) = self.inline_param_type
+            && parse_inline_param_type(&original) == Some(param_type)
+        {
+            return original;
+        }
+
+        let padding = if self.flags1 & Self::FLAG_01 != 0 {
+            b' '
+        } else {
+            0
+        };
+        let mut bytes = [padding; INLINE_PARAM_TYPE_SIZE];
+        let name = param_type.as_bytes();
+        let len = name.len().min(INLINE_PARAM_TYPE_SIZE - 1);
+        bytes[..len].copy_from_slice(&name[..len]);
+        bytes[len] = 0;
+        bytes
+    }
+}
+
+/// Parses the name out of an inline param type header field.
+fn parse_inline_param_type(bytes: &[u8]) -> Option<&str> {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    std::str::from_utf8(&bytes[..end])
+        .ok()
+        .map(|name| name.trim_end_matches(' '))
+}
+
+/// A `.param` file read from disk, with rows of type [T].
+///
+/// This is the on-disk counterpart of the param data that
+/// `FD4ParamRepository` holds in memory. Unlike the in-memory version, it
+/// doesn't need a running game, so it can be used to inspect or patch params
+/// offline.
+#[derive(Debug, Clone)]
+pub struct ParamFile<T: ParamLayout> {
+    /// The name of the paramdef used by this file. This is the same as
+    /// [ParamLayout::NAME] for the row type.
+    pub param_type: String,
+
+    /// The version of the paramdef used to write the rows.
+    pub paramdef_version: u16,
+
+    /// The format used to lay out the file.
+    pub format: ParamFormat,
+
+    /// Each row's ID, name, and data, in the order they appear in the file.
+    /// The game expects rows to be sorted by ID.
+    pub rows: Vec<(u32, Option<String>, T)>,
+}
+
+impl<T: ParamLayout + Default> ParamFile<T> {
+    /// Creates an empty param file in the given [format].
+    pub fn new(paramdef_version: u16, format: ParamFormat) -> Self {
+        Self {
+            param_type: T::NAME.to_string(),
+            paramdef_version,
+            format,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Parses a `.param` file from [data].
+    ///
+    /// Returns an error if the file isn't for the param described by [T].
+    /// When a param has several layouts, [ParamFileInfo] can be used to pick
+    /// the one to read it with.
+    pub fn read(data: &[u8]) -> ParamFileResult<Self> {
+        let reader = Reader(data);
+        let Header {
+            mut format,
+            strings_offset,
+            paramdef_version,
+            row_count,
+            data_start,
+            param_type,
+            param_type_end,
+        } = Header::read(&reader)?;
+        if param_type != T::NAME {
+            return Err(ParamFileError::ParamTypeMismatch {
+                expected: T::NAME,
+                actual: param_type,
+            });
+        }
+
+        let row_size = size_of::<T>();
+        let descriptors_end = format.header_size() + row_count * format.descriptor_size();
+        format.descriptor_padding = data_start.saturating_sub(descriptors_end);
+
+        let mut rows = Vec::with_capacity(row_count);
+        let mut data_end = data_start;
+        let mut strings_end = param_type_end.unwrap_or(strings_offset);
+        let mut first_name = None;
+        for i in 0..row_count {
+            let descriptor = format.header_size() + i * format.descriptor_size();
+            let id = reader.u32(descriptor)?;
+            let (data_offset, name_offset) = if format.is_long() {
+                (
+                    reader.u64(descriptor + 0x8)? as usize,
+                    reader.u64(descriptor + 0x10)? as usize,
+                )
+            } else {
+                (
+                    reader.u32(descriptor + 0x4)? as usize,
+                    reader.u32(descriptor + 0x8)? as usize,
+                )
+            };
+
+            if i == 1 && data_offset > data_start && data_offset - data_start != row_size {
+                return Err(ParamFileError::RowSizeMismatch {
+                    expected: row_size,
+                    actual: data_offset - data_start,
+                });
+            }
+
+            let mut row = T::default();
+            row.as_bytes_mut()
+                .copy_from_slice(reader.bytes(data_offset, row_size)?);
+            data_end = data_end.max(data_offset + row_size);
+
+            let name = if name_offset == 0 {
+                None
+            } else {
+                let (name, end) = if format.has_unicode_row_names() {
+                    reader.utf16(name_offset)?
+                } else {
+                    reader.shift_jis(name_offset)?
+                };
+                first_name.get_or_insert(name_offset);
+                strings_end = strings_end.max(end);
+                Some(name)
+            };
+
+            rows.push((id, name, row));
+        }
+
+        format.data_padding = strings_offset.saturating_sub(data_end);
+        if let (Some(param_type_end), Some(first_name)) = (param_type_end, first_name) {
+            format.names_padding = first_name.saturating_sub(param_type_end);
+        }
+        format.trailing_padding = data.len().saturating_sub(strings_end);
+
+        Ok(Self {
+            param_type,
+            paramdef_version,
+            format,
+            rows,
+        })
+    }
+
+    /// Serializes this param file to bytes.
+    ///
+    /// A file that was read with [read](Self::read) and not modified is
+    /// written back byte-for-byte identically.
+    pub fn write(&self) -> Vec<u8> {
+        let format = &self.format;
+        let row_count = self.rows.len();
+        let row_size = size_of::<T>();
+
+        let descriptors_end = format.header_size() + row_count * format.descriptor_size();
+        let data_start = descriptors_end + format.descriptor_padding;
+        let strings_offset = data_start + row_count * row_size + format.data_padding;
+
+        let mut strings = Vec::new();
+        if format.has_offset_param_type() {
+            strings.extend_from_slice(self.param_type.as_bytes());
+            strings.push(0);
+            strings.resize(strings.len() + format.names_padding, 0);
+        }
+        let name_offsets = self
+            .rows
+            .iter()
+            .map(|(_, name, _)| {
+                let name = name.as_ref()?;
+                let offset = strings_offset + strings.len();
+                if format.has_unicode_row_names() {
+                    for unit in name.encode_utf16().chain([0]) {
+                        strings.extend_from_slice(&unit.to_le_bytes());
+                    }
+                } else {
+                    strings.extend_from_slice(&SHIFT_JIS.encode(name).0);
+                    strings.push(0);
+                }
+                Some(offset)
+            })
+            .collect::<Vec<_>>();
+        strings.resize(strings.len() + format.trailing_padding, 0);
+
+        let mut out = vec![0; strings_offset];
+        out[0x0..0x4].copy_from_slice(&(strings_offset as u32).to_le_bytes());
+        if !format.is_long() && !format.has_int_data_offset() {
+            out[0x4..0x6].copy_from_slice(&(data_start as u16).to_le_bytes());
+        }
+        out[0x6..0x8].copy_from_slice(&format.unk06.to_le_bytes());
+        out[0x8..0xA].copy_from_slice(&self.paramdef_version.to_le_bytes());
+        out[0xA..0xC].copy_from_slice(&(row_count as u16).to_le_bytes());
+        if format.has_offset_param_type() {
+            out[0x10..0x18].copy_from_slice(&(strings_offset as u64).to_le_bytes());
+        } else {
+            out[0xC..0x2C].copy_from_slice(&format.inline_param_type(&self.param_type));
+        }
+        out[0x2D] = format.flags1;
+        out[0x2E] = format.flags2;
+        out[0x2F] = format.paramdef_format_version;
+        if format.is_long() {
+            out[0x30..0x38].copy_from_slice(&(data_start as u64).to_le_bytes());
+        } else if format.has_int_data_offset() {
+            out[0x30..0x34].copy_from_slice(&(data_start as u32).to_le_bytes());
+        }
+
+        for (i, ((id, _, row), name_offset)) in self.rows.iter().zip(name_offsets).enumerate() {
+            let descriptor = format.header_size() + i * format.descriptor_size();
+            let data_offset = data_start + i * row_size;
+            let name_offset = name_offset.unwrap_or(0);
+            out[descriptor..descriptor + 4].copy_from_slice(&id.to_le_bytes());
+            if format.is_long() {
+                out[descriptor + 0x8..descriptor + 0x10]
+                    .copy_from_slice(&(data_offset as u64).to_le_bytes());
+                out[descriptor + 0x10..descriptor + 0x18]
+                    .copy_from_slice(&(name_offset as u64).to_le_bytes());
+            } else {
+                out[descriptor + 0x4..descriptor + 0x8]
+                    .copy_from_slice(&(data_offset as u32).to_le_bytes());
+                out[descriptor + 0x8..descriptor + 0xC]
+                    .copy_from_slice(&(name_offset as u32).to_le_bytes());
+            }
+            out[data_offset..data_offset + row_size].copy_from_slice(row.as_bytes());
+        }
+
+        out.extend_from_slice(&strings);
+        out
+    }
+}
+
+/// The header fields of a `.param` file, which describe what it contains
+/// and which layout its rows use.
+///
+/// This can be read without knowing the file's row type, so it's useful for
+/// choosing among the layouts a param has had over time. Paramdefs record
+/// the paramdef version of their latest layout as
+/// [ParamLayout::PARAMDEF_VERSION], and every layout has its own row size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamFileInfo {
+    /// The name of the paramdef used by the file, such as
+    /// `EQUIP_PARAM_WEAPON_ST`.
+    pub param_type: String,
+
+    /// The version of the paramdef used to write the rows.
+    pub paramdef_version: u16,
+
+    /// The size in bytes of each row, or `None` if the file has fewer than
+    /// two rows and so doesn't say.
+    pub row_size: Option<usize>,
+}
+
+impl ParamFileInfo {
+    /// Reads the header of the `.param` file in [data].
+    pub fn read(data: &[u8]) -> ParamFileResult<Self> {
+        let reader = Reader(data);
+        let header = Header::read(&reader)?;
+
+        let row_size = if header.row_count < 2 {
+            None
+        } else {
+            let format = &header.format;
+            let descriptor = format.header_size() + format.descriptor_size();
+            let data_offset = if format.is_long() {
+                reader.u64(descriptor + 0x8)? as usize
+            } else {
+                reader.u32(descriptor + 0x4)? as usize
+            };
+            data_offset.checked_sub(header.data_start)
+        };
+
+        Ok(Self {
+            param_type: header.param_type,
+            paramdef_version: header.paramdef_version,
+            row_size,
+        })
+    }
+
+    /// Returns whether this file's rows use the layout [T].
+    ///
+    /// The file must be for [T]'s paramdef and, if it says, have rows the
+    /// size of [T]. If [T] knows its paramdef version, the file must also
+    /// have been written with that version or a later one that didn't change
+    /// the row size.
+    pub fn uses_layout<T: ParamLayout>(&self) -> bool {
+        self.param_type == T::NAME
+            && self.row_size.is_none_or(|size| size == size_of::<T>())
+            && T::PARAMDEF_VERSION.is_none_or(|version| version <= self.paramdef_version)
+    }
+}
+
+/// The fields of a `.param` file's header that are needed to read the rest
+/// of it.
+struct Header {
+    format: ParamFormat,
+    strings_offset: usize,
+    paramdef_version: u16,
+    row_count: usize,
+    data_start: usize,
+    param_type: String,
+
+    /// The offset just past the param type name, if it's stored in the
+    /// string table.
+    param_type_end: Option<usize>,
+}
+
+impl Header {
+    fn read(reader: &Reader<'_>) -> ParamFileResult<Self> {
+        if reader.u8(0x2C)? != 0 {
+            return Err(ParamFileError::BigEndian);
+        }
+
+        let mut format = ParamFormat::new(reader.u8(0x2D)?, reader.u8(0x2E)?, reader.u8(0x2F)?);
+        format.unk06 = reader.u16(0x6)?;
+
+        let strings_offset = reader.u32(0x0)? as usize;
+        let paramdef_version = reader.u16(0x8)?;
+        let row_count = reader.u16(0xA)? as usize;
+        let data_start = if format.is_long() {
+            reader.u64(0x30)? as usize
+        } else if format.has_int_data_offset() {
+            reader.u32(0x30)? as usize
+        } else {
+            reader.u16(0x4)? as usize
+        };
+
+        let (param_type, param_type_end) = if format.has_offset_param_type() {
+            let offset = reader.u64(0x10)? as usize;
+            let param_type = reader.ascii(offset)?;
+            (param_type.to_string(), Some(offset + param_type.len() + 1))
+        } else {
+            let bytes: [u8; INLINE_PARAM_TYPE_SIZE] = reader
+                .bytes(0xC, INLINE_PARAM_TYPE_SIZE)?
+                .try_into()
+                .unwrap();
+            format.inline_param_type = Some(bytes);
+            let param_type =
+                parse_inline_param_type(&bytes).ok_or(ParamFileError::InvalidString(0xC))?;
+            (param_type.to_string(), None)
+        };
+
+        Ok(Self {
+            format,
+            strings_offset,
+            paramdef_version,
+            row_count,
+            data_start,
+            param_type,
+            param_type_end,
+        })
+    }
+}
+
+/// A bounds-checked little-endian reader over a param file.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&self, offset: usize, len: usize) -> ParamFileResult<&'a [u8]> {
+        self.0
+            .get(offset..offset + len)
+            .ok_or(ParamFileError::Truncated(offset + len))
+    }
+
+    fn array<const N: usize>(&self, offset: usize) -> ParamFileResult<[u8; N]> {
+        Ok(self.bytes(offset, N)?.try_into().unwrap())
+    }
+
+    fn u8(&self, offset: usize) -> ParamFileResult<u8> {
+        Ok(self.bytes(offset, 1)?[0])
+    }
+
+    fn u16(&self, offset: usize) -> ParamFileResult<u16> {
+        Ok(u16::from_le_bytes(self.array(offset)?))
+    }
+
+    fn u32(&self, offset: usize) -> ParamFileResult<u32> {
+        Ok(u32::from_le_bytes(self.array(offset)?))
+    }
+
+    fn u64(&self, offset: usize) -> ParamFileResult<u64> {
+        Ok(u64::from_le_bytes(self.array(offset)?))
+    }
+
+    /// Reads a null-terminated ASCII string.
+    fn ascii(&self, offset: usize) -> ParamFileResult<&'a str> {
+        let bytes = self
+            .0
+            .get(offset..)
+            .ok_or(ParamFileError::Truncated(offset))?;
+        let len = bytes
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or(ParamFileError::Truncated(self.0.len()))?;
+        std::str::from_utf8(&bytes[..len]).map_err(|_| ParamFileError::InvalidString(offset))
+    }
+
+    /// Reads a null-terminated Shift-JIS string. Returns the string and the
+    /// offset just past its terminator.
+    fn shift_jis(&self, offset: usize) -> ParamFileResult<(String, usize)> {
+        let bytes = self
+            .0
+            .get(offset..)
+            .ok_or(ParamFileError::Truncated(offset))?;
+        let len = bytes
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or(ParamFileError::Truncated(self.0.len()))?;
+        let (string, had_errors) = SHIFT_JIS.decode_without_bom_handling(&bytes[..len]);
+        if had_errors {
+            return Err(ParamFileError::InvalidString(offset));
+        }
+        Ok((string.into_owned(), offset + len + 1))
+    }
+
+    /// Reads a null-terminated UTF-16 string. Returns the string and the
+    /// offset just past its terminator.
+    fn utf16(&self, offset: usize) -> ParamFileResult<(String, usize)> {
+        let mut units = Vec::new();
+        let mut end = offset;
+        loop {
+            let unit = self.u16(end)?;
+            end += 2;
+            if unit == 0 {
+                break;
+            }
+            units.push(unit);
+        }
+        let string =
+            String::from_utf16(&units).map_err(|_| ParamFileError::InvalidString(offset))?;
+        Ok((string, end))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ParamFieldInfo;
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct TestRow([u8; 8]);
+
+    impl ParamLayout for TestRow {
+        const NAME: &str = "TEST_PARAM_ST";
+        const FIELDS: &[ParamFieldInfo] = &[];
+
+        fn as_bytes(&self) -> &[u8] {
+            &self.0
+        }
+
+        fn as_bytes_mut(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
+    }
+
+    fn test_file(format: ParamFormat) -> ParamFile<TestRow> {
+        let mut file = ParamFile::new(3, format);
+        file.rows
+            .push((10, Some("First".to_string()), TestRow([1; 8])));
+        file.rows.push((20, None, TestRow([2; 8])));
+        file.rows
+            .push((30, Some("三番目".to_string()), TestRow([3; 8])));
+        file
+    }
+
+    fn assert_round_trip(format: ParamFormat) {
+        let file = test_file(format);
+        let bytes = file.write();
+        let read = ParamFile::<TestRow>::read(&bytes).unwrap();
+        assert_eq!(read.param_type, "TEST_PARAM_ST");
+        assert_eq!(read.paramdef_version, 3);
+        assert_eq!(read.rows.len(), 3);
+        for (expected, actual) in file.rows.iter().zip(&read.rows) {
+            assert_eq!(expected, actual);
+        }
+        assert_eq!(read.write(), bytes);
+    }
+
+    #[test]
+    fn round_trip_long() {
+        assert_round_trip(ParamFormat::new(
+            ParamFormat::FLAG_01 | ParamFormat::LONG_DATA_OFFSET | ParamFormat::OFFSET_PARAM_TYPE,
+            ParamFormat::UNICODE_ROW_NAMES,
+            5,
+        ));
+    }
+
+    #[test]
+    fn round_trip_int() {
+        assert_round_trip(ParamFormat::new(
+            ParamFormat::FLAG_01 | ParamFormat::INT_DATA_OFFSET,
+            0,
+            3,
+        ));
+    }
+
+    #[test]
+    fn round_trip_short() {
+        assert_round_trip(ParamFormat::new(0, 0, 1));
+    }
+
+    #[test]
+    fn preserves_padding() {
+        let mut bytes = test_file(ParamFormat::new(
+            ParamFormat::FLAG_01 | ParamFormat::LONG_DATA_OFFSET | ParamFormat::OFFSET_PARAM_TYPE,
+            ParamFormat::UNICODE_ROW_NAMES,
+            5,
+        ))
+        .write();
+        bytes.extend_from_slice(&[0; 5]);
+
+        let read = ParamFile::<TestRow>::read(&bytes).unwrap();
+        assert_eq!(read.write(), bytes);
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct OldTestRow([u8; 4]);
+
+    impl ParamLayout for OldTestRow {
+        const NAME: &str = "TEST_PARAM_ST";
+        const FIELDS: &[ParamFieldInfo] = &[];
+
+        fn as_bytes(&self) -> &[u8] {
+            &self.0
+        }
+
+        fn as_bytes_mut(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct NewTestRow([u8; 8]);
+
+    impl ParamLayout for NewTestRow {
+        const NAME: &str = "TEST_PARAM_ST";
+        const FIELDS: &[ParamFieldInfo] = &[];
+        const PARAMDEF_VERSION: Option<u16> = Some(3);
+
+        fn as_bytes(&self) -> &[u8] {
+            &self.0
+        }
+
+        fn as_bytes_mut(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
+    }
+
+    #[test]
+    fn info() {
+        for format in [
+            ParamFormat::new(ParamFormat::LONG_DATA_OFFSET, 0, 5),
+            ParamFormat::new(ParamFormat::FLAG_01 | ParamFormat::INT_DATA_OFFSET, 0, 3),
+            ParamFormat::new(0, 0, 1),
+        ] {
+            let info = ParamFileInfo::read(&test_file(format).write()).unwrap();
+            assert_eq!(
+                info,
+                ParamFileInfo {
+                    param_type: "TEST_PARAM_ST".to_string(),
+                    paramdef_version: 3,
+                    row_size: Some(8),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn chooses_layout() {
+        let format = ParamFormat::new(ParamFormat::LONG_DATA_OFFSET, 0, 5);
+        let info = ParamFileInfo::read(&test_file(format.clone()).write()).unwrap();
+        assert!(info.uses_layout::<NewTestRow>());
+        assert!(!info.uses_layout::<OldTestRow>());
+
+        let mut old = ParamFile::new(2, format);
+        old.rows.push((10, None, OldTestRow([1; 4])));
+        old.rows.push((20, None, OldTestRow([2; 4])));
+        let info = ParamFileInfo::read(&old.write()).unwrap();
+        assert!(info.uses_layout::<OldTestRow>());
+        assert!(!info.uses_layout::<NewTestRow>());
+
+        // Without a row size to go on, only the paramdef version can rule out
+        // a layout.
+        old.rows.pop();
+        let info = ParamFileInfo::read(&old.write()).unwrap();
+        assert_eq!(info.row_size, None);
+        assert!(info.uses_layout::<OldTestRow>());
+        assert!(!info.uses_layout::<NewTestRow>());
+    }
+
+    #[test]
+    fn rejects_other_params() {
+        let mut file = test_file(ParamFormat::new(ParamFormat::LONG_DATA_OFFSET, 0, 5));
+        file.param_type = "OTHER_PARAM_ST".to_string();
+        assert!(matches!(
+            ParamFile::<TestRow>::read(&file.write()),
+            Err(ParamFileError::ParamTypeMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+
+use encoding_rs::SHIFT_JIS;
+use thiserror::Error;
+
+/// An error indicating that a string can't be stored in a `fixstr` field
+/// because it contains characters that Shift-JIS can't represent.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{0:?} can't be encoded as Shift-JIS")]
+pub struct FixstrEncodeError(pub String);
+
+/// Decodes the contents of a `fixstr` field, a Shift-JIS string that ends at
+/// the first null byte or at the end of the field.
+///
+/// Invalid sequences are replaced with U+FFFD. This only allocates if the
+/// string contains non-ASCII characters.
+pub fn decode_fixstr(bytes: &[u8]) -> Cow<'_, str> {
+    let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    SHIFT_JIS.decode_without_bom_handling(&bytes[..len]).0
+}
+
+/// Decodes the contents of a `fixstrW` field, a UTF-16 string that ends at
+/// the first null code unit or at the end of the field.
+///
+/// Unpaired surrogates are replaced with U+FFFD.
+pub fn decode_fixstr_w(units: &[u16]) -> String {
+    let len = units.iter().position(|u| *u == 0).unwrap_or(units.len());
+    String::from_utf16_lossy(&units[..len])
+}
+
+/// Encodes [value] as Shift-JIS into a `fixstr` field.
+///
+/// If [value] is too long for [dest], it's truncated to the last whole
+/// character that fits. The rest of [dest] is filled with nulls. Nothing is
+/// written if this returns an error.
+pub fn encode_fixstr(value: &str, dest: &mut [u8]) -> Result<(), FixstrEncodeError> {
+    let mut encoded = Vec::with_capacity(dest.len());
+    let mut buffer = [0; 4];
+    for c in value.chars() {
+        let (bytes, _, had_errors) = SHIFT_JIS.encode(c.encode_utf8(&mut buffer));
+        if had_errors {
+            return Err(FixstrEncodeError(value.to_string()));
+        }
+        if encoded.len() + bytes.len() > dest.len() {
+            break;
+        }
+        encoded.extend_from_slice(&bytes);
+    }
+
+    dest[..encoded.len()].copy_from_slice(&encoded);
+    dest[encoded.len()..].fill(0);
+    Ok(())
+}
+
+/// Encodes [value] as UTF-16 into a `fixstrW` field.
+///
+/// If [value] is too long for [dest], it's truncated to the last whole
+/// character that fits, so surrogate pairs are never split. The rest of
+/// [dest] is filled with nulls.
+pub fn encode_fixstr_w(value: &str, dest: &mut [u16]) {
+    let mut len = 0;
+    let mut buffer = [0; 2];
+    for c in value.chars() {
+        let units = c.encode_utf16(&mut buffer);
+        if len + units.len() > dest.len() {
+            break;
+        }
+        dest[len..len + units.len()].copy_from_slice(units);
+        len += units.len();
+    }
+    dest[len..].fill(0);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fixstr_round_trip() {
+        let mut field = [0xFF; 8];
+        encode_fixstr("c2010", &mut field).unwrap();
+        assert_eq!(&field, b"c2010\0\0\0");
+        assert!(matches!(decode_fixstr(&field), Cow::Borrowed("c2010")));
+
+        encode_fixstr("剣士", &mut field).unwrap();
+        assert_eq!(&field[..4], &[0x8C, 0x95, 0x8E, 0x6D]);
+        assert_eq!(decode_fixstr(&field), "剣士");
+    }
+
+    #[test]
+    fn fixstr_truncates_whole_characters() {
+        let mut field = [0; 5];
+        encode_fixstr("剣士剣", &mut field).unwrap();
+        assert_eq!(decode_fixstr(&field), "剣士");
+        assert_eq!(field[4], 0);
+
+        encode_fixstr("abcdefg", &mut field).unwrap();
+        assert_eq!(&field, b"abcde");
+        assert_eq!(decode_fixstr(&field), "abcde");
+    }
+
+    #[test]
+    fn fixstr_rejects_unencodable() {
+        let mut field = *b"old\0";
+        assert_eq!(
+            encode_fixstr("🗡", &mut field),
+            Err(FixstrEncodeError("🗡".to_string()))
+        );
+        assert_eq!(&field, b"old\0");
+    }
+
+    #[test]
+    fn fixstr_w_round_trip() {
+        let mut field = [0xFFFF; 6];
+        encode_fixstr_w("Torch", &mut field);
+        assert_eq!(decode_fixstr_w(&field), "Torch");
+        assert_eq!(field[5], 0);
+
+        encode_fixstr_w("a🗡🗡", &mut field);
+        assert_eq!(decode_fixstr_w(&field), "a🗡🗡");
+
+        encode_fixstr_w("abc🗡🗡", &mut field);
+        assert_eq!(decode_fixstr_w(&field), "abc🗡");
+        assert_eq!(field[5], 0);
+    }
+}
@@ -0,0 +1,19 @@
+use super::ParamLayout;
+
+/// A typed ID of a row in a particular param.
+///
+/// Each game crate generates an implementation of this for every param that
+/// other params' fields refer to, so that IDs for different params can't be
+/// mixed up.
+pub trait ParamId: Copy {
+    /// The name of the param the row is in, such as `SpEffectParam`. Several
+    /// params may share a paramdef, so this is more specific than
+    /// [ParamLayout::NAME].
+    const PARAM: &str;
+
+    /// The type of the rows in the param.
+    type Row: ParamLayout;
+
+    /// Returns the raw row ID. Negative IDs usually mean "no row".
+    fn row_id(self) -> i32;
+}
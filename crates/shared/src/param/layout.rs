@@ -0,0 +1,151 @@
+use super::{ParamFieldInfo, ParamVersion};
+
+/// A type whose memory layout matches the rows of a particular param.
+///
+/// Each game crate generates an implementation of this for every paramdef, so
+/// code that only needs to move rows around as bytes can be shared between
+/// games.
+pub trait ParamLayout: Sized {
+    /// The all-caps, snake-case name of the parameter definition.
+    const NAME: &str;
+
+    /// Information about every field in this param's rows, in the order they
+    /// appear in memory.
+    const FIELDS: &[ParamFieldInfo];
+
+    /// The first paramdef version whose rows use this layout, or `None` if
+    /// this is the earliest known layout.
+    const FIRST_VERSION: Option<ParamVersion> = None;
+
+    /// The first paramdef version whose rows no longer use this layout, or
+    /// `None` if this is the latest layout.
+    const REMOVED_VERSION: Option<ParamVersion> = None;
+
+    /// The paramdef version stored in the headers of `.param` files whose
+    /// rows use this layout, or `None` if it isn't known.
+    ///
+    /// Paramdefs only record this for their latest layout, so older layouts
+    /// leave it unset. See [ParamFileInfo::uses_layout](crate::ParamFileInfo::uses_layout).
+    const PARAMDEF_VERSION: Option<u16> = None;
+
+    /// The paramdef names of this param's fields in the order that the
+    /// paramdex meta's `AlternativeOrder` lists them for display, with `-`
+    /// entries separating groups of related fields. This is empty when the
+    /// params were generated without meta files or the meta gives no order.
+    const DISPLAY_ORDER: &[&str] = &[];
+
+    /// Returns whether rows written for the regulation [version] use this
+    /// layout.
+    ///
+    /// When a paramdef has changed over time, the latest layout is generated
+    /// at the top level of each game's `param` module and older layouts are
+    /// generated in `before_{version}` submodules. This can be used to pick
+    /// the right one at runtime. To pick one from a `.param` file on its own,
+    /// use [ParamFileInfo](crate::ParamFileInfo) instead.
+    fn supports_version(version: ParamVersion) -> bool {
+        Self::FIRST_VERSION.is_none_or(|first| first <= version)
+            && Self::REMOVED_VERSION.is_none_or(|removed| version < removed)
+    }
+
+    /// Returns this param's fields in [Self::DISPLAY_ORDER], followed by any
+    /// fields it leaves out in their order in memory. Without a display
+    /// order, this is just [Self::FIELDS].
+    fn display_fields() -> Vec<&'static ParamFieldInfo> {
+        let mut fields = Self::DISPLAY_ORDER
+            .iter()
+            .filter_map(|name| Self::FIELDS.iter().find(|field| field.name == *name))
+            .collect::<Vec<_>>();
+        for field in Self::FIELDS {
+            if !fields.iter().any(|listed| listed.name == field.name) {
+                fields.push(field);
+            }
+        }
+        fields
+    }
+
+    /// Returns the raw bytes of this row.
+    fn as_bytes(&self) -> &[u8];
+
+    /// Returns the raw bytes of this row mutably. Every byte pattern is a
+    /// valid row, although not necessarily one the game will accept.
+    fn as_bytes_mut(&mut self) -> &mut [u8];
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ParamFieldType;
+
+    struct OldRow;
+
+    impl ParamLayout for OldRow {
+        const NAME: &str = "OLD_ROW";
+        const FIELDS: &[ParamFieldInfo] = &[];
+        const FIRST_VERSION: Option<ParamVersion> = Some(ParamVersion(10310059));
+        const REMOVED_VERSION: Option<ParamVersion> = Some(ParamVersion(11210015));
+
+        fn as_bytes(&self) -> &[u8] {
+            &[]
+        }
+
+        fn as_bytes_mut(&mut self) -> &mut [u8] {
+            &mut []
+        }
+    }
+
+    struct OrderedRow;
+
+    impl ParamLayout for OrderedRow {
+        const NAME: &str = "ORDERED_ROW";
+        const FIELDS: &[ParamFieldInfo] = &[field("first"), field("second"), field("third")];
+        const DISPLAY_ORDER: &[&str] = &["third", "-", "first", "missing"];
+
+        fn as_bytes(&self) -> &[u8] {
+            &[]
+        }
+
+        fn as_bytes_mut(&mut self) -> &mut [u8] {
+            &mut []
+        }
+    }
+
+    const fn field(name: &'static str) -> ParamFieldInfo {
+        ParamFieldInfo {
+            name,
+            rust_name: name,
+            display_name: None,
+            alt_name: None,
+            field_type: ParamFieldType::U8,
+            offset: 0,
+            bit_offset: 0,
+            bit_width: None,
+            array_len: None,
+            minimum: None,
+            maximum: None,
+            hidden: false,
+        }
+    }
+
+    #[test]
+    fn display_fields() {
+        let names = OrderedRow::display_fields()
+            .into_iter()
+            .map(|field| field.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["third", "first", "second"]);
+
+        let names = OldRow::display_fields()
+            .into_iter()
+            .map(|field| field.name)
+            .collect::<Vec<_>>();
+        assert!(names.is_empty());
+    }
+
+    #[test]
+    fn supports_version() {
+        assert!(!OldRow::supports_version(ParamVersion(10200000)));
+        assert!(OldRow::supports_version(ParamVersion(10310059)));
+        assert!(OldRow::supports_version(ParamVersion(11100011)));
+        assert!(!OldRow::supports_version(ParamVersion(11210015)));
+    }
+}
@@ -0,0 +1,247 @@
+use std::io;
+
+use aes::{
+    Aes256,
+    cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit, block_padding::NoPadding},
+};
+use thiserror::Error;
+
+use super::{ParamFile, ParamFileError, ParamLayout, ParamVersion};
+
+mod bnd4;
+mod dcx;
+
+pub use bnd4::*;
+pub use dcx::*;
+
+/// The size of an AES block, which is also the size of the IV that prefixes
+/// encrypted regulation files.
+const BLOCK_SIZE: usize = 16;
+
+/// An error returned when reading a regulation file.
+#[derive(Error, Debug)]
+pub enum RegulationError {
+    /// The file refers to data beyond its end.
+    #[error("Regulation file is truncated")]
+    Truncated,
+
+    /// The encrypted data isn't a whole number of AES blocks.
+    #[error("Encrypted data is {0} bytes, which isn't a multiple of the AES block size")]
+    InvalidCiphertext(usize),
+
+    /// The decrypted data isn't a DCX container. This usually means the wrong
+    /// key was used.
+    #[error("Invalid DCX header. Is the decryption key correct?")]
+    InvalidDcx,
+
+    /// The DCX container uses a compression algorithm other than DFLT or ZSTD.
+    #[error("Unsupported DCX compression {0:?}")]
+    UnsupportedCompression(String),
+
+    /// The decompressed data isn't a valid BND4 binder.
+    #[error("Invalid BND4 binder: {0}")]
+    InvalidBnd4(&'static str),
+
+    /// The binder doesn't contain a param with the given name.
+    #[error("No param named {0}")]
+    MissingParam(String),
+
+    /// A param inside the binder couldn't be parsed.
+    #[error("Error reading {name}: {source}")]
+    Param {
+        name: String,
+        #[source]
+        source: ParamFileError,
+    },
+
+    /// Decompression or compression failed.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// A [Result] whose error type is [RegulationError].
+pub type RegulationResult<T> = Result<T, RegulationError>;
+
+/// A decrypted and unpacked regulation file, such as Elden Ring's
+/// `regulation.bin` or Dark Souls III's `Data0.bdt`.
+///
+/// This holds the individual `.param` files, which can be parsed with
+/// [ParamFile] and the param structs generated for each game. Writing it back
+/// out recompresses and re-encrypts it with the same key and format.
+#[derive(Debug, Clone)]
+pub struct Regulation {
+    /// The binder containing the `.param` files.
+    pub binder: Bnd4,
+
+    /// The format of the DCX container around the binder.
+    dcx: DcxFormat,
+
+    /// The key the file was encrypted with.
+    key: [u8; 32],
+
+    /// The IV the file was encrypted with.
+    iv: [u8; BLOCK_SIZE],
+}
+
+impl Regulation {
+    /// The AES key for Elden Ring's `regulation.bin`.
+    pub const ELDEN_RING_KEY: [u8; 32] = [
+        0x99, 0xBF, 0xFC, 0x36, 0x6A, 0x6B, 0xC8, 0xC6, 0xF5, 0x82, 0x7D, 0x09, 0x36, 0x02, 0xD6,
+        0x76, 0xC4, 0x28, 0x92, 0xA0, 0x1C, 0x20, 0x7F, 0xB0, 0x24, 0xD3, 0xAF, 0x4E, 0x49, 0x3F,
+        0xEF, 0x99,
+    ];
+
+    /// The AES key for Dark Souls III's `Data0.bdt`.
+    pub const DARK_SOULS_3_KEY: [u8; 32] = *b"ds3#jn/8_7(rsY9pg55GFN7VFL#+3n/)";
+
+    /// Decrypts and unpacks a regulation file using [key].
+    ///
+    /// There's no constant for Nightreign's key yet, since it hasn't been
+    /// checked against a real `regulation.bin`. Its key can still be passed
+    /// here directly.
+    pub fn read(data: &[u8], key: &[u8; 32]) -> RegulationResult<Self> {
+        let iv: [u8; BLOCK_SIZE] = data
+            .get(..BLOCK_SIZE)
+            .and_then(|iv| iv.try_into().ok())
+            .ok_or(RegulationError::Truncated)?;
+
+        let mut decrypted = data[BLOCK_SIZE..].to_vec();
+        if !decrypted.len().is_multiple_of(BLOCK_SIZE) {
+            return Err(RegulationError::InvalidCiphertext(decrypted.len()));
+        }
+        cbc::Decryptor::<Aes256>::new(key.into(), &iv.into())
+            .decrypt_padded_mut::<NoPadding>(&mut decrypted)
+            .expect("data is a multiple of the block size");
+
+        let (decompressed, dcx) = dcx_decompress(&decrypted)?;
+        Ok(Self {
+            binder: Bnd4::read(&decompressed)?,
+            dcx,
+            key: *key,
+            iv,
+        })
+    }
+
+    /// Repacks, recompresses, and re-encrypts this regulation file.
+    pub fn write(&self) -> RegulationResult<Vec<u8>> {
+        let mut compressed = dcx_compress(&self.binder.write(), &self.dcx)?;
+        let len = compressed.len();
+        compressed.resize(len.next_multiple_of(BLOCK_SIZE), 0);
+
+        let mut out = self.iv.to_vec();
+        let len = compressed.len();
+        let encrypted = cbc::Encryptor::<Aes256>::new(&self.key.into(), &self.iv.into())
+            .encrypt_padded_mut::<NoPadding>(&mut compressed, len)
+            .expect("data was padded to the block size");
+        out.extend_from_slice(encrypted);
+        Ok(out)
+    }
+
+    /// Returns the regulation version, which determines which layout each
+    /// param uses. See [ParamLayout::supports_version].
+    pub fn version(&self) -> Option<ParamVersion> {
+        self.binder.version().parse().ok().map(ParamVersion)
+    }
+
+    /// Returns the names of all the params in this file, such as
+    /// `EquipParamWeapon`.
+    pub fn param_names(&self) -> impl Iterator<Item = &str> {
+        self.binder.files.iter().filter_map(|file| {
+            let path = file.name.as_deref()?;
+            let name = path.rsplit(['\\', '/']).next()?;
+            name.strip_suffix(".param")
+        })
+    }
+
+    /// Returns the raw contents of the param named [name], such as
+    /// `EquipParamWeapon`.
+    pub fn param_data(&self, name: &str) -> Option<&[u8]> {
+        self.binder
+            .file(&format!("{name}.param"))
+            .map(|file| file.data.as_slice())
+    }
+
+    /// Parses the param named [name] as rows of type [T].
+    pub fn read_param<T: ParamLayout + Default>(
+        &self,
+        name: &str,
+    ) -> RegulationResult<ParamFile<T>> {
+        let data = self
+            .param_data(name)
+            .ok_or_else(|| RegulationError::MissingParam(name.to_string()))?;
+        ParamFile::read(data).map_err(|source| RegulationError::Param {
+            name: name.to_string(),
+            source,
+        })
+    }
+
+    /// Replaces the contents of the param named [name] with [param].
+    pub fn set_param<T: ParamLayout + Default>(
+        &mut self,
+        name: &str,
+        param: &ParamFile<T>,
+    ) -> RegulationResult<()> {
+        let file = self
+            .binder
+            .file_mut(&format!("{name}.param"))
+            .ok_or_else(|| RegulationError::MissingParam(name.to_string()))?;
+        file.data = param.write();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let regulation = Regulation {
+            binder: bnd4::test::binder(),
+            dcx: dcx::test::format(DcxCompression::Zstd),
+            key: Regulation::ELDEN_RING_KEY,
+            iv: [7; BLOCK_SIZE],
+        };
+
+        let bytes = regulation.write().unwrap();
+        let read = Regulation::read(&bytes, &Regulation::ELDEN_RING_KEY).unwrap();
+        assert_eq!(
+            read.param_names().collect::<Vec<_>>(),
+            ["EquipParamWeapon", "SpEffectParam", "Empty"]
+        );
+        assert_eq!(read.param_data("EquipParamWeapon").unwrap(), b"weapons");
+        assert_eq!(read.write().unwrap(), bytes);
+    }
+
+    #[test]
+    fn round_trip_other_key() {
+        let key = [0x5A; 32];
+        let regulation = Regulation {
+            binder: bnd4::test::binder(),
+            dcx: dcx::test::format(DcxCompression::Deflate),
+            key,
+            iv: [7; BLOCK_SIZE],
+        };
+
+        let bytes = regulation.write().unwrap();
+        let read = Regulation::read(&bytes, &key).unwrap();
+        assert_eq!(read.param_data("SpEffectParam").unwrap(), b"speffects");
+        assert_eq!(read.write().unwrap(), bytes);
+    }
+
+    #[test]
+    fn wrong_key() {
+        let regulation = Regulation {
+            binder: bnd4::test::binder(),
+            dcx: dcx::test::format(DcxCompression::Deflate),
+            key: Regulation::ELDEN_RING_KEY,
+            iv: [7; BLOCK_SIZE],
+        };
+
+        let bytes = regulation.write().unwrap();
+        assert!(matches!(
+            Regulation::read(&bytes, &Regulation::DARK_SOULS_3_KEY),
+            Err(RegulationError::InvalidDcx)
+        ));
+    }
+}
@@ -0,0 +1,580 @@
+use encoding_rs::SHIFT_JIS;
+
+use super::{RegulationError, RegulationResult};
+
+/// The size of the BND4 header.
+const HEADER_SIZE: usize = 0x40;
+
+/// Normalized format bit indicating that files have IDs.
+const FORMAT_IDS: u8 = 0x02;
+/// Normalized format bit indicating that files have names. If it's the only
+/// bit set, each file header also ends with a second copy of the ID.
+const FORMAT_NAMES1: u8 = 0x04;
+/// Normalized format bit indicating that files have names.
+const FORMAT_NAMES2: u8 = 0x08;
+/// Normalized format bit indicating that data offsets are 64-bit.
+const FORMAT_LONG_OFFSETS: u8 = 0x10;
+/// Normalized format bit indicating that file headers include uncompressed
+/// sizes.
+const FORMAT_COMPRESSION: u8 = 0x20;
+
+/// Normalized file flag indicating that a file's data is DCX-compressed.
+const FILE_COMPRESSED: u8 = 0x01;
+
+/// The value of the extended header byte for binders with a hash table.
+const EXTENDED_HASH_TABLE: u8 = 4;
+
+/// A single file within a [Bnd4].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bnd4File {
+    /// The file's ID, or -1 if the binder doesn't store IDs.
+    pub id: i32,
+
+    /// The file's path, such as
+    /// `N:\GR\data\Param\param\GameParam\EquipParamWeapon.param`.
+    pub name: Option<String>,
+
+    /// The file's raw flags byte, as stored on disk.
+    pub flags: u8,
+
+    /// The file's contents.
+    pub data: Vec<u8>,
+}
+
+/// A BND4 binder, the archive format used to bundle params into a
+/// regulation file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bnd4 {
+    /// The files in the binder, in the order they're stored.
+    pub files: Vec<Bnd4File>,
+
+    /// The original header. Counts and offsets are recalculated when writing.
+    header: [u8; HEADER_SIZE],
+
+    /// The original hash table and the file names it was built from, so it
+    /// can be reused as long as the names haven't changed.
+    hash_table: Option<(Vec<u8>, Vec<Option<String>>)>,
+}
+
+impl Bnd4 {
+    /// Parses a BND4 binder from [data].
+    pub fn read(data: &[u8]) -> RegulationResult<Self> {
+        let reader = Reader(data);
+        let header: [u8; HEADER_SIZE] = reader.bytes(0, HEADER_SIZE)?.try_into().unwrap();
+        if &header[0x0..0x4] != b"BND4" {
+            return Err(RegulationError::InvalidBnd4("missing magic"));
+        }
+        if header[0x9] != 0 {
+            return Err(RegulationError::InvalidBnd4(
+                "big-endian binders aren't supported",
+            ));
+        }
+
+        let file_count = reader.u32(0xC)? as usize;
+        let file_header_size = reader.u64(0x20)? as usize;
+        let headers_end = reader.u64(0x28)? as usize;
+        let unicode = header[0x30] != 0;
+        let format = Bnd4::format(&header);
+        let hash_table_offset = reader.u64(0x38)? as usize;
+
+        // The file count isn't trusted to preallocate, since a corrupt count
+        // could ask for far more memory than the binder could hold.
+        let mut files = Vec::new();
+        for i in 0..file_count {
+            let mut offset = i
+                .checked_mul(file_header_size)
+                .and_then(|offset| offset.checked_add(HEADER_SIZE))
+                .ok_or(RegulationError::Truncated)?;
+            // Checking the whole header up front means none of the field
+            // offsets below can overflow.
+            reader.bytes(offset, file_header_size)?;
+            let flags = reader.u8(offset)?;
+            if normalize_file_flags(&header, flags) & FILE_COMPRESSED != 0 {
+                return Err(RegulationError::InvalidBnd4(
+                    "compressed files aren't supported",
+                ));
+            }
+
+            let size = reader.u64(offset + 0x8)? as usize;
+            offset += 0x10;
+            if format & FORMAT_COMPRESSION != 0 {
+                offset += 8;
+            }
+
+            let data_offset = if format & FORMAT_LONG_OFFSETS != 0 {
+                offset += 8;
+                reader.u64(offset - 8)? as usize
+            } else {
+                offset += 4;
+                reader.u32(offset - 4)? as usize
+            };
+
+            let mut id = -1;
+            if format & FORMAT_IDS != 0 {
+                id = reader.u32(offset)? as i32;
+                offset += 4;
+            }
+
+            let mut name = None;
+            if format & (FORMAT_NAMES1 | FORMAT_NAMES2) != 0 {
+                let name_offset = reader.u32(offset)? as usize;
+                offset += 4;
+                name = Some(if unicode {
+                    reader.utf16(name_offset)?
+                } else {
+                    reader.shift_jis(name_offset)?
+                });
+            }
+
+            if format == FORMAT_NAMES1 {
+                id = reader.u32(offset)? as i32;
+            }
+
+            files.push(Bnd4File {
+                id,
+                name,
+                flags,
+                data: reader.bytes(data_offset, size)?.to_vec(),
+            });
+        }
+
+        let hash_table = if header[0x32] == EXTENDED_HASH_TABLE {
+            let len = headers_end
+                .checked_sub(hash_table_offset)
+                .ok_or(RegulationError::Truncated)?;
+            let table = reader.bytes(hash_table_offset, len)?;
+            let names = files.iter().map(|file| file.name.clone()).collect();
+            Some((table.to_vec(), names))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            files,
+            header,
+            hash_table,
+        })
+    }
+
+    /// Serializes this binder to bytes.
+    pub fn write(&self) -> Vec<u8> {
+        let format = Bnd4::format(&self.header);
+        let unicode = self.header[0x30] != 0;
+        let file_header_size = file_header_size(format);
+
+        let mut out = self.header.to_vec();
+        out[0xC..0x10].copy_from_slice(&(self.files.len() as u32).to_le_bytes());
+        out[0x20..0x28].copy_from_slice(&(file_header_size as u64).to_le_bytes());
+        out.resize(HEADER_SIZE + self.files.len() * file_header_size, 0);
+
+        // Returns the offset of each field in the file header for file [i].
+        let field = |i: usize, skip: usize| HEADER_SIZE + i * file_header_size + skip;
+        let data_offset_field = if format & FORMAT_COMPRESSION != 0 {
+            0x18
+        } else {
+            0x10
+        };
+        let id_field = data_offset_field
+            + if format & FORMAT_LONG_OFFSETS != 0 {
+                8
+            } else {
+                4
+            };
+        let name_field = id_field + if format & FORMAT_IDS != 0 { 4 } else { 0 };
+
+        for (i, file) in self.files.iter().enumerate() {
+            out[field(i, 0)] = file.flags;
+            out[field(i, 0x4)..field(i, 0x8)].copy_from_slice(&(-1i32).to_le_bytes());
+            out[field(i, 0x8)..field(i, 0x10)]
+                .copy_from_slice(&(file.data.len() as u64).to_le_bytes());
+            if format & FORMAT_COMPRESSION != 0 {
+                out[field(i, 0x10)..field(i, 0x18)]
+                    .copy_from_slice(&(file.data.len() as u64).to_le_bytes());
+            }
+            if format & FORMAT_IDS != 0 {
+                out[field(i, id_field)..field(i, id_field + 4)]
+                    .copy_from_slice(&file.id.to_le_bytes());
+            }
+            if format == FORMAT_NAMES1 {
+                out[field(i, name_field + 4)..field(i, name_field + 8)]
+                    .copy_from_slice(&file.id.to_le_bytes());
+            }
+        }
+
+        if format & (FORMAT_NAMES1 | FORMAT_NAMES2) != 0 {
+            for (i, file) in self.files.iter().enumerate() {
+                let offset = out.len() as u32;
+                out[field(i, name_field)..field(i, name_field + 4)]
+                    .copy_from_slice(&offset.to_le_bytes());
+
+                let name = file.name.as_deref().unwrap_or_default();
+                if unicode {
+                    for unit in name.encode_utf16().chain([0]) {
+                        out.extend_from_slice(&unit.to_le_bytes());
+                    }
+                } else {
+                    out.extend_from_slice(&SHIFT_JIS.encode(name).0);
+                    out.push(0);
+                }
+            }
+        }
+
+        if self.header[0x32] == EXTENDED_HASH_TABLE {
+            pad(&mut out, 0x8);
+            let offset = out.len();
+            out[0x38..0x40].copy_from_slice(&(offset as u64).to_le_bytes());
+            match &self.hash_table {
+                Some((table, names))
+                    if names.iter().eq(self.files.iter().map(|file| &file.name)) =>
+                {
+                    out.extend_from_slice(table);
+                }
+                _ => self.write_hash_table(&mut out),
+            }
+        }
+
+        let headers_end = out.len() as u64;
+        out[0x28..0x30].copy_from_slice(&headers_end.to_le_bytes());
+
+        for (i, file) in self.files.iter().enumerate() {
+            if !file.data.is_empty() {
+                pad(&mut out, 0x10);
+            }
+
+            let offset = out.len();
+            if format & FORMAT_LONG_OFFSETS != 0 {
+                out[field(i, data_offset_field)..field(i, data_offset_field + 8)]
+                    .copy_from_slice(&(offset as u64).to_le_bytes());
+            } else {
+                out[field(i, data_offset_field)..field(i, data_offset_field + 4)]
+                    .copy_from_slice(&(offset as u32).to_le_bytes());
+            }
+            out.extend_from_slice(&file.data);
+        }
+
+        out
+    }
+
+    /// Returns the binder's version string. For regulation files, this is
+    /// the regulation version.
+    pub fn version(&self) -> &str {
+        let version = &self.header[0x18..0x20];
+        let len = version
+            .iter()
+            .position(|b| *b == 0)
+            .unwrap_or(version.len());
+        std::str::from_utf8(&version[..len]).unwrap_or_default()
+    }
+
+    /// Returns the file with the given name, ignoring case and the directory.
+    /// For example, `EquipParamWeapon.param` matches
+    /// `N:\GR\data\Param\param\GameParam\EquipParamWeapon.param`.
+    pub fn file(&self, name: &str) -> Option<&Bnd4File> {
+        self.files.iter().find(|file| file_name_matches(file, name))
+    }
+
+    /// Like [file](Self::file), but returns a mutable reference.
+    pub fn file_mut(&mut self, name: &str) -> Option<&mut Bnd4File> {
+        self.files
+            .iter_mut()
+            .find(|file| file_name_matches(file, name))
+    }
+
+    /// Writes a new hash table for the current file names to the end of [out].
+    ///
+    /// This uses the same algorithm and layout as SoulsFormats: the paths are
+    /// hashed, and the hashes are bucketed into a prime number of groups. The
+    /// table starts with the offset of its path hashes, the group count, and
+    /// a constant, followed by each group's length and first index and then
+    /// the path hashes themselves.
+    fn write_hash_table(&self, out: &mut Vec<u8>) {
+        let group_count = (self.files.len() as u32 / 7..)
+            .find(|n| is_prime(*n))
+            .unwrap();
+
+        let mut groups = vec![Vec::new(); group_count as usize];
+        for (i, file) in self.files.iter().enumerate() {
+            let hash = path_hash(file.name.as_deref().unwrap_or_default());
+            groups[(hash % group_count) as usize].push((hash, i as i32));
+        }
+
+        let start = out.len();
+        let path_hashes_offset = start + 0x10 + groups.len() * 8;
+        out.extend_from_slice(&(path_hashes_offset as u64).to_le_bytes());
+        out.extend_from_slice(&group_count.to_le_bytes());
+        out.extend_from_slice(&0x00080810u32.to_le_bytes());
+
+        let mut index = 0i32;
+        for group in &mut groups {
+            group.sort();
+            out.extend_from_slice(&(group.len() as i32).to_le_bytes());
+            out.extend_from_slice(&index.to_le_bytes());
+            index += group.len() as i32;
+        }
+
+        for (hash, file_index) in groups.iter().flatten() {
+            out.extend_from_slice(&hash.to_le_bytes());
+            out.extend_from_slice(&file_index.to_le_bytes());
+        }
+    }
+
+    /// Returns the normalized format flags from [header].
+    ///
+    /// The format byte is stored with its bits reversed unless the binder is
+    /// bit-big-endian or the byte looks like it's already in big-endian order.
+    fn format(header: &[u8; HEADER_SIZE]) -> u8 {
+        let raw = header[0x31];
+        let bit_big_endian = header[0xA] == 0;
+        if bit_big_endian || (raw & 1 != 0 && raw & 0x80 == 0) {
+            raw
+        } else {
+            raw.reverse_bits()
+        }
+    }
+}
+
+/// Returns the normalized file flags for a file in a binder with [header].
+fn normalize_file_flags(header: &[u8; HEADER_SIZE], flags: u8) -> u8 {
+    if header[0xA] == 0 {
+        flags
+    } else {
+        flags.reverse_bits()
+    }
+}
+
+/// Returns the size of each file header for binders with [format].
+fn file_header_size(format: u8) -> usize {
+    let mut size = 0x14;
+    if format & FORMAT_COMPRESSION != 0 {
+        size += 8;
+    }
+    if format & FORMAT_LONG_OFFSETS != 0 {
+        size += 4;
+    }
+    if format & FORMAT_IDS != 0 {
+        size += 4;
+    }
+    if format & (FORMAT_NAMES1 | FORMAT_NAMES2) != 0 {
+        size += 4;
+    }
+    if format == FORMAT_NAMES1 {
+        size += 8;
+    }
+    size
+}
+
+/// Returns whether [file]'s name ends with [name], ignoring case.
+fn file_name_matches(file: &Bnd4File, name: &str) -> bool {
+    let Some(path) = file.name.as_deref() else {
+        return false;
+    };
+    let file_name = path.rsplit(['\\', '/']).next().unwrap_or(path);
+    file_name.eq_ignore_ascii_case(name)
+}
+
+/// Pads [out] with zeros to a multiple of [alignment].
+fn pad(out: &mut Vec<u8>, alignment: usize) {
+    out.resize(out.len().next_multiple_of(alignment), 0);
+}
+
+/// Hashes a binder path the way FromSoftware does.
+fn path_hash(path: &str) -> u32 {
+    let path = path.trim().replace('\\', "/").to_lowercase();
+    let prefix = if path.starts_with('/') { "" } else { "/" };
+    prefix
+        .chars()
+        .chain(path.chars())
+        .fold(0u32, |hash, c| hash.wrapping_mul(37).wrapping_add(c as u32))
+}
+
+fn is_prime(n: u32) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|i| i * i <= n)
+            .all(|i| !n.is_multiple_of(i))
+}
+
+/// A bounds-checked little-endian reader over a binder.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&self, offset: usize, len: usize) -> RegulationResult<&'a [u8]> {
+        let end = offset.checked_add(len).ok_or(RegulationError::Truncated)?;
+        self.0.get(offset..end).ok_or(RegulationError::Truncated)
+    }
+
+    fn u8(&self, offset: usize) -> RegulationResult<u8> {
+        Ok(self.bytes(offset, 1)?[0])
+    }
+
+    fn u32(&self, offset: usize) -> RegulationResult<u32> {
+        Ok(u32::from_le_bytes(
+            self.bytes(offset, 4)?.try_into().unwrap(),
+        ))
+    }
+
+    fn u64(&self, offset: usize) -> RegulationResult<u64> {
+        Ok(u64::from_le_bytes(
+            self.bytes(offset, 8)?.try_into().unwrap(),
+        ))
+    }
+
+    fn shift_jis(&self, offset: usize) -> RegulationResult<String> {
+        let bytes = self.0.get(offset..).ok_or(RegulationError::Truncated)?;
+        let len = bytes
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or(RegulationError::Truncated)?;
+        Ok(SHIFT_JIS
+            .decode_without_bom_handling(&bytes[..len])
+            .0
+            .into_owned())
+    }
+
+    fn utf16(&self, offset: usize) -> RegulationResult<String> {
+        let mut units = Vec::new();
+        let mut offset = offset;
+        loop {
+            let bytes = self.bytes(offset, 2)?;
+            let unit = u16::from_le_bytes([bytes[0], bytes[1]]);
+            if unit == 0 {
+                break;
+            }
+            units.push(unit);
+            offset += 2;
+        }
+        Ok(String::from_utf16_lossy(&units))
+    }
+}
+
+#[cfg(test)]
+pub(super) mod test {
+    use super::*;
+
+    /// Returns a binder laid out like Elden Ring's regulation.
+    pub fn binder() -> Bnd4 {
+        let mut header = [0u8; HEADER_SIZE];
+        header[0x0..0x4].copy_from_slice(b"BND4");
+        header[0xA] = 1;
+        header[0x10..0x18].copy_from_slice(&0x40u64.to_le_bytes());
+        header[0x18..0x20].copy_from_slice(b"07D7R6\0\0");
+        header[0x30] = 1;
+        // IDs | Names1 | Names2 | Compression, bit-reversed.
+        header[0x31] = 0x74;
+        header[0x32] = EXTENDED_HASH_TABLE;
+
+        let file = |id: i32, name: &str, data: &[u8]| Bnd4File {
+            id,
+            name: Some(format!(
+                "N:\\GR\\data\\Param\\param\\GameParam\\{name}.param"
+            )),
+            flags: 0x40,
+            data: data.to_vec(),
+        };
+        let binder = Bnd4 {
+            files: vec![
+                file(0, "EquipParamWeapon", b"weapons"),
+                file(1, "SpEffectParam", b"speffects"),
+                file(2, "Empty", b""),
+            ],
+            header,
+            hash_table: None,
+        };
+        Bnd4::read(&binder.write()).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let binder = binder();
+        assert_eq!(binder.files.len(), 3);
+        assert_eq!(binder.files[1].id, 1);
+        assert_eq!(
+            binder.file("spEffectParam.param").unwrap().data,
+            b"speffects"
+        );
+        assert_eq!(binder.files[2].data, b"");
+        assert!(binder.hash_table.is_some());
+        assert_eq!(binder.version(), "07D7R6");
+
+        let bytes = binder.write();
+        assert_eq!(Bnd4::read(&bytes).unwrap().write(), bytes);
+    }
+
+    #[test]
+    fn renamed_file_rebuilds_hash_table() {
+        let mut binder = binder();
+        let original = binder.write();
+        binder.files[0].name = Some("EquipParamProtector.param".to_string());
+
+        let bytes = binder.write();
+        assert_ne!(bytes, original);
+        let read = Bnd4::read(&bytes).unwrap();
+        assert_eq!(
+            read.files[0].name.as_deref(),
+            Some("EquipParamProtector.param")
+        );
+        assert_eq!(read.file("SpEffectParam.param").unwrap().data, b"speffects");
+    }
+
+    #[test]
+    fn rebuilt_hash_table_indexes_every_name() {
+        let mut binder = binder();
+        binder.files[0].name = Some("EquipParamProtector.param".to_string());
+        binder.files.push(Bnd4File {
+            id: 3,
+            name: Some("NpcParam.param".to_string()),
+            flags: 0x40,
+            data: b"npcs".to_vec(),
+        });
+        let bytes = binder.write();
+
+        let reader = Reader(&bytes);
+        let table = reader.u64(0x38).unwrap() as usize;
+        let path_hashes = reader.u64(table).unwrap() as usize;
+        let group_count = reader.u32(table + 0x8).unwrap();
+        assert_eq!(reader.u32(table + 0xC).unwrap(), 0x00080810);
+        assert_eq!(path_hashes, table + 0x10 + group_count as usize * 8);
+
+        let mut indexed = Vec::new();
+        for group in 0..group_count {
+            let entry = table + 0x10 + group as usize * 8;
+            let len = reader.u32(entry).unwrap() as usize;
+            let start = reader.u32(entry + 4).unwrap() as usize;
+            for i in start..start + len {
+                let hash = reader.u32(path_hashes + i * 8).unwrap();
+                let file = reader.u32(path_hashes + i * 8 + 4).unwrap() as usize;
+                assert_eq!(hash % group_count, group);
+                assert_eq!(hash, path_hash(binder.files[file].name.as_deref().unwrap()));
+                indexed.push(file);
+            }
+        }
+        indexed.sort();
+        assert_eq!(indexed, (0..binder.files.len()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn malformed_binder_is_an_error() {
+        let bytes = binder().write();
+
+        let mut huge_count = bytes.clone();
+        huge_count[0xC..0x10].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            Bnd4::read(&huge_count),
+            Err(RegulationError::Truncated)
+        ));
+
+        let mut huge_header = bytes.clone();
+        huge_header[0x20..0x28].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(matches!(
+            Bnd4::read(&huge_header),
+            Err(RegulationError::Truncated)
+        ));
+
+        let mut early_end = bytes.clone();
+        early_end[0x28..0x30].copy_from_slice(&0u64.to_le_bytes());
+        assert!(matches!(
+            Bnd4::read(&early_end),
+            Err(RegulationError::Truncated)
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+use std::io::{Read, Write};
+
+use flate2::{Compression, read::ZlibDecoder, write::ZlibEncoder};
+
+use super::{RegulationError, RegulationResult};
+
+/// The size of the DCX header that precedes the compressed data.
+const HEADER_SIZE: usize = 0x4C;
+
+/// The compression algorithms used by DCX containers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DcxCompression {
+    /// zlib-wrapped deflate, used by Dark Souls III and Elden Ring.
+    Deflate,
+
+    /// Zstandard, used by Nightreign.
+    Zstd,
+}
+
+/// The format of a DCX container, recorded when it's decompressed so that the
+/// same header can be used when compressing it again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DcxFormat {
+    /// The compression algorithm.
+    pub compression: DcxCompression,
+
+    /// The original header. Only the size fields are updated when
+    /// recompressing.
+    header: [u8; HEADER_SIZE],
+}
+
+impl DcxFormat {
+    /// The compression level stored in the header.
+    fn level(&self) -> u8 {
+        self.header[0x30]
+    }
+}
+
+/// Decompresses a DCX container and returns its contents along with its format.
+pub fn dcx_decompress(data: &[u8]) -> RegulationResult<(Vec<u8>, DcxFormat)> {
+    let header: [u8; HEADER_SIZE] = data
+        .get(..HEADER_SIZE)
+        .and_then(|header| header.try_into().ok())
+        .ok_or(RegulationError::Truncated)?;
+    if &header[0x0..0x4] != b"DCX\0"
+        || &header[0x18..0x1C] != b"DCS\0"
+        || &header[0x24..0x28] != b"DCP\0"
+        || &header[0x44..0x48] != b"DCA\0"
+    {
+        return Err(RegulationError::InvalidDcx);
+    }
+
+    let compression = match &header[0x28..0x2C] {
+        b"DFLT" => DcxCompression::Deflate,
+        b"ZSTD" => DcxCompression::Zstd,
+        other => {
+            return Err(RegulationError::UnsupportedCompression(
+                String::from_utf8_lossy(other).into_owned(),
+            ));
+        }
+    };
+
+    let uncompressed_size = u32::from_be_bytes(header[0x1C..0x20].try_into().unwrap()) as usize;
+    let compressed_size = u32::from_be_bytes(header[0x20..0x24].try_into().unwrap()) as usize;
+    let compressed = data
+        .get(HEADER_SIZE..HEADER_SIZE + compressed_size)
+        .ok_or(RegulationError::Truncated)?;
+
+    let mut decompressed = Vec::with_capacity(uncompressed_size);
+    match compression {
+        DcxCompression::Deflate => {
+            ZlibDecoder::new(compressed).read_to_end(&mut decompressed)?;
+        }
+        DcxCompression::Zstd => {
+            zstd::stream::copy_decode(compressed, &mut decompressed)?;
+        }
+    }
+
+    Ok((
+        decompressed,
+        DcxFormat {
+            compression,
+            header,
+        },
+    ))
+}
+
+/// Compresses [data] into a DCX container with the given [format].
+pub fn dcx_compress(data: &[u8], format: &DcxFormat) -> RegulationResult<Vec<u8>> {
+    let mut out = format.header.to_vec();
+    match format.compression {
+        DcxCompression::Deflate => {
+            let mut encoder = ZlibEncoder::new(out, Compression::new(format.level().min(9).into()));
+            encoder.write_all(data)?;
+            out = encoder.finish()?;
+        }
+        DcxCompression::Zstd => {
+            let level = match format.level() {
+                0 => zstd::DEFAULT_COMPRESSION_LEVEL,
+                level => level.into(),
+            };
+            zstd::stream::copy_encode(data, &mut out, level)?;
+        }
+    }
+
+    let compressed_size = (out.len() - HEADER_SIZE) as u32;
+    out[0x1C..0x20].copy_from_slice(&(data.len() as u32).to_be_bytes());
+    out[0x20..0x24].copy_from_slice(&compressed_size.to_be_bytes());
+    Ok(out)
+}
+
+#[cfg(test)]
+pub(super) mod test {
+    use super::*;
+
+    /// Returns a DCX format using [compression].
+    pub fn format(compression: DcxCompression) -> DcxFormat {
+        let mut header = [0u8; HEADER_SIZE];
+        header[0x0..0x4].copy_from_slice(b"DCX\0");
+        header[0x18..0x1C].copy_from_slice(b"DCS\0");
+        header[0x24..0x28].copy_from_slice(b"DCP\0");
+        header[0x28..0x2C].copy_from_slice(match compression {
+            DcxCompression::Deflate => b"DFLT",
+            DcxCompression::Zstd => b"ZSTD",
+        });
+        header[0x30] = 9;
+        header[0x44..0x48].copy_from_slice(b"DCA\0");
+        DcxFormat {
+            compression,
+            header,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for compression in [DcxCompression::Deflate, DcxCompression::Zstd] {
+            let data = b"regulation".repeat(100);
+            let compressed = dcx_compress(&data, &format(compression)).unwrap();
+            let (decompressed, read_format) = dcx_decompress(&compressed).unwrap();
+            assert_eq!(decompressed, data);
+            assert_eq!(read_format.compression, compression);
+        }
+    }
+
+    #[test]
+    fn unsupported_compression() {
+        let mut compressed = dcx_compress(b"data", &format(DcxCompression::Deflate)).unwrap();
+        compressed[0x28..0x2C].copy_from_slice(b"KRAK");
+        assert!(matches!(
+            dcx_decompress(&compressed),
+            Err(RegulationError::UnsupportedCompression(name)) if name == "KRAK"
+        ));
+    }
+}
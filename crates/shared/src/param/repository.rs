@@ -0,0 +1,224 @@
+use super::{ParamData, ParamHeader, ParamId, ParamLayout};
+
+/// A resource in a game's param repository that holds a single param.
+pub trait ParamResCap {
+    /// The header of the game's params.
+    type Header: ParamHeader;
+
+    /// The param this resource holds.
+    fn data(&self) -> &ParamData<Self::Header>;
+
+    /// Like [Self::data], but mutable.
+    fn data_mut(&mut self) -> &mut ParamData<Self::Header>;
+
+    /// Whether this resource's name, which is the name of the param it holds,
+    /// is [name].
+    fn has_name(&self, name: &str) -> bool;
+}
+
+/// The param data held by [R]'s res caps.
+type HeldParam<R> = ParamData<<R as ParamResCap>::Header>;
+
+/// The res-cap holder of a game's `FD4ParamRepository`, which holds a
+/// [ParamResCap] for each param the game has loaded.
+///
+/// Each game crate implements this for its own holder, and its repository
+/// looks params up through the provided methods so that every game finds
+/// them the same way.
+///
+/// Lookups take the position of the first param with the requested paramdef
+/// in the repository, if the game's paramdex records one. The param at that
+/// position is checked first, and the whole repository is only searched if
+/// it doesn't match, since the repository isn't guaranteed to hold its
+/// params in that order.
+pub trait ParamResCapHolder {
+    /// The type of the res caps in the holder.
+    type ResCap: ParamResCap;
+
+    /// Iterates over every res cap in the holder.
+    fn res_caps(&self) -> impl Iterator<Item = &Self::ResCap>;
+
+    /// Like [Self::res_caps], but mutable.
+    fn res_caps_mut(&mut self) -> impl Iterator<Item = &mut Self::ResCap>;
+
+    /// Returns the position of the first param whose paramdef is [T] and, if
+    /// [name] is given, whose resource name is [name]. If [index] is given,
+    /// the param at that position is checked first.
+    fn position<T: ParamLayout>(&self, index: Option<usize>, name: Option<&str>) -> Option<usize> {
+        let matches = |res_cap: &Self::ResCap| {
+            res_cap.data().has_paramdef::<T>() && name.is_none_or(|name| res_cap.has_name(name))
+        };
+        if let Some(index) = index
+            && self.res_caps().nth(index).is_some_and(matches)
+        {
+            return Some(index);
+        }
+        self.res_caps().position(matches)
+    }
+
+    /// Finds the first param whose paramdef is [T] and, if [name] is given,
+    /// whose resource name is [name]. See [Self::position].
+    fn find<T: ParamLayout>(
+        &self,
+        index: Option<usize>,
+        name: Option<&str>,
+    ) -> Option<&Self::ResCap> {
+        let position = self.position::<T>(index, name)?;
+        self.res_caps().nth(position)
+    }
+
+    /// Like [Self::find], but mutable.
+    fn find_mut<T: ParamLayout>(
+        &mut self,
+        index: Option<usize>,
+        name: Option<&str>,
+    ) -> Option<&mut Self::ResCap> {
+        let position = self.position::<T>(index, name)?;
+        self.res_caps_mut().nth(position)
+    }
+
+    /// Retrieve the first param whose paramdef is [T], if its rows are laid
+    /// out as [T] expects.
+    fn param<T: ParamLayout>(&self, index: Option<usize>) -> Option<&HeldParam<Self::ResCap>> {
+        self.find::<T>(index, None)?.data().checked::<T>()
+    }
+
+    /// Like [Self::param], but mutable.
+    fn param_mut<T: ParamLayout>(
+        &mut self,
+        index: Option<usize>,
+    ) -> Option<&mut HeldParam<Self::ResCap>> {
+        self.find_mut::<T>(index, None)?
+            .data_mut()
+            .checked_mut::<T>()
+    }
+
+    /// Retrieve a row of the first param whose paramdef is [T] mutably.
+    fn get_mut<T: ParamLayout>(&mut self, index: Option<usize>, id: u32) -> Option<&mut T> {
+        // SAFETY: the paramdef name and row size were checked.
+        unsafe { self.param_mut::<T>(index)?.get_mut(id) }
+    }
+
+    /// Iterates over every row of the first param whose paramdef is [T]
+    /// mutably, yielding each row's ID, name, and data in ID order.
+    fn rows_mut<'a, T: ParamLayout + 'a>(
+        &'a mut self,
+        index: Option<usize>,
+    ) -> impl Iterator<Item = (u32, Option<String>, &'a mut T)> {
+        self.param_mut::<T>(index)
+            .into_iter()
+            // SAFETY: the paramdef name and row size were checked.
+            .flat_map(|data| unsafe { data.rows_mut::<T>() })
+    }
+
+    /// Retrieve the row that a typed ID refers to, from the param named by
+    /// [I::PARAM](ParamId::PARAM).
+    fn resolve<I: ParamId>(&self, index: Option<usize>, id: I) -> Option<&I::Row> {
+        let row_id = u32::try_from(id.row_id()).ok()?;
+        let res_cap = self.find::<I::Row>(index, Some(I::PARAM))?;
+
+        // SAFETY: the paramdef name and row size were checked.
+        unsafe { res_cap.data().checked::<I::Row>()?.get(row_id) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::param::data::test::{TestHeader, TestRow, as_param, as_param_mut, param_file};
+
+    /// A res cap holding a test param file.
+    struct TestResCap {
+        name: &'static str,
+        file: Vec<u64>,
+    }
+
+    impl ParamResCap for TestResCap {
+        type Header = TestHeader;
+
+        fn data(&self) -> &ParamData<TestHeader> {
+            as_param(&self.file)
+        }
+
+        fn data_mut(&mut self) -> &mut ParamData<TestHeader> {
+            as_param_mut(&mut self.file)
+        }
+
+        fn has_name(&self, name: &str) -> bool {
+            self.name == name
+        }
+    }
+
+    struct TestHolder(Vec<TestResCap>);
+
+    impl ParamResCapHolder for TestHolder {
+        type ResCap = TestResCap;
+
+        fn res_caps(&self) -> impl Iterator<Item = &TestResCap> {
+            self.0.iter()
+        }
+
+        fn res_caps_mut(&mut self) -> impl Iterator<Item = &mut TestResCap> {
+            self.0.iter_mut()
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct TestId(i32);
+
+    impl ParamId for TestId {
+        const PARAM: &str = "SecondTestParam";
+        type Row = TestRow;
+
+        fn row_id(self) -> i32 {
+            self.0
+        }
+    }
+
+    fn holder() -> TestHolder {
+        TestHolder(vec![
+            TestResCap {
+                name: "TestParam",
+                file: param_file(&[(10, None, 1), (20, None, 2)]),
+            },
+            TestResCap {
+                name: "SecondTestParam",
+                file: param_file(&[(10, None, 3), (20, None, 4)]),
+            },
+        ])
+    }
+
+    #[test]
+    fn finds_params_by_index_or_search() {
+        let holder = holder();
+        assert_eq!(holder.position::<TestRow>(Some(1), None), Some(1));
+        assert_eq!(holder.position::<TestRow>(Some(5), None), Some(0));
+        assert_eq!(holder.position::<TestRow>(None, None), Some(0));
+        assert_eq!(
+            holder.position::<TestRow>(Some(0), Some("SecondTestParam")),
+            Some(1)
+        );
+        assert_eq!(holder.position::<TestRow>(None, Some("Missing")), None);
+
+        let param = holder.param::<TestRow>(None).unwrap();
+        assert_eq!(param.ids().collect::<Vec<_>>(), [10, 20]);
+    }
+
+    #[test]
+    fn edits_and_resolves_rows() {
+        let mut holder = holder();
+        *holder.get_mut::<TestRow>(Some(1), 20).unwrap() = TestRow([9; 8]);
+        for (_, _, row) in holder.rows_mut::<TestRow>(None) {
+            row.0[0] = 0;
+        }
+
+        assert_eq!(holder.resolve(None, TestId(20)), Some(&TestRow([9; 8])));
+        assert_eq!(holder.resolve(None, TestId(30)), None);
+        assert_eq!(holder.resolve(None, TestId(-1)), None);
+        let first = holder.param::<TestRow>(None).unwrap().handle::<TestRow>();
+        assert_eq!(
+            first.unwrap().get(10),
+            Some(&TestRow([0, 1, 1, 1, 1, 1, 1, 1]))
+        );
+    }
+}
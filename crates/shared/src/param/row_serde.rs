@@ -0,0 +1,432 @@
+use std::fmt;
+
+use serde::{
+    Deserializer, Serialize, Serializer,
+    de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeSeq, SerializeStruct},
+};
+
+use super::{
+    ParamFieldInfo, ParamFieldType, ParamFieldValue, ParamLayout, decode_fixstr, decode_fixstr_w,
+    encode_fixstr, encode_fixstr_w,
+};
+
+/// Serializes [row] as a struct whose keys are the Rust accessor names of its
+/// visible fields. Padding and other hidden fields are skipped.
+///
+/// Single-bit fields are serialized as bools and `fixstr`/`fixstrW` fields as
+/// strings, matching the types of their accessors. Other arrays are
+/// serialized as sequences.
+///
+/// The generated param structs use this for their `Serialize`
+/// implementations when the `serde` feature is enabled.
+pub fn serialize_row<T: ParamLayout, S: Serializer>(
+    row: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let bytes = row.as_bytes();
+    let fields = T::FIELDS.iter().filter(|field| !field.hidden);
+    let mut state = serializer.serialize_struct(T::NAME, fields.clone().count())?;
+    for field in fields {
+        state.serialize_field(field.rust_name, &FieldRef { field, bytes })?;
+    }
+    state.end()
+}
+
+/// Deserializes a row serialized by [serialize_row]. Fields that are missing
+/// from the input keep their default values.
+///
+/// The generated param structs use this for their `Deserialize`
+/// implementations when the `serde` feature is enabled.
+pub fn deserialize_row<'de, T: ParamLayout + Default, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let mut row = T::default();
+    patch_row(&mut row, deserializer)?;
+    Ok(row)
+}
+
+/// Overwrites the fields of [row] that are present in the input, and leaves
+/// the rest unchanged. This is useful for applying patches that only list
+/// the fields they change.
+///
+/// Fields may be named by either their Rust accessor names or their paramdef
+/// names. Unknown and hidden fields are errors, as are values that don't fit
+/// in their fields. Strings that are too long are truncated like they are by
+/// the generated setters.
+pub fn patch_row<'de, T: ParamLayout, D: Deserializer<'de>>(
+    row: &mut T,
+    deserializer: D,
+) -> Result<(), D::Error> {
+    deserializer.deserialize_map(RowVisitor {
+        fields: T::FIELDS,
+        bytes: row.as_bytes_mut(),
+    })
+}
+
+/// A single field of a row, for serialization.
+struct FieldRef<'a> {
+    field: &'a ParamFieldInfo,
+    bytes: &'a [u8],
+}
+
+impl Serialize for FieldRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let field = self.field;
+        if field.bit_width == Some(1) {
+            let value = field.read(self.bytes).as_f64().unwrap_or_default();
+            return serializer.serialize_bool(value != 0.0);
+        }
+
+        let bytes = &self.bytes[field.offset..field.offset + field.size()];
+        match (field.field_type, field.array_len) {
+            (ParamFieldType::Fixstr, Some(_)) => serializer.serialize_str(&decode_fixstr(bytes)),
+            (ParamFieldType::FixstrW, Some(_)) => {
+                serializer.serialize_str(&decode_fixstr_w(&utf16_units(bytes)))
+            }
+            _ => field.read(self.bytes).serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for ParamFieldValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::U8(v) => serializer.serialize_u8(*v),
+            Self::S8(v) => serializer.serialize_i8(*v),
+            Self::U16(v) => serializer.serialize_u16(*v),
+            Self::S16(v) => serializer.serialize_i16(*v),
+            Self::U32(v) => serializer.serialize_u32(*v),
+            Self::S32(v) => serializer.serialize_i32(*v),
+            Self::F32(v) => serializer.serialize_f32(*v),
+            Self::Array(elements) => {
+                let mut seq = serializer.serialize_seq(Some(elements.len()))?;
+                for element in elements {
+                    seq.serialize_element(element)?;
+                }
+                seq.end()
+            }
+        }
+    }
+}
+
+/// Visits a map of field names to values, writing each to a row.
+struct RowVisitor<'a> {
+    fields: &'static [ParamFieldInfo],
+    bytes: &'a mut [u8],
+}
+
+impl<'de> Visitor<'de> for RowVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of param field names to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            let Some(field) = self
+                .fields
+                .iter()
+                .find(|field| !field.hidden && (field.rust_name == key || field.name == key))
+            else {
+                return Err(de::Error::custom(format!("unknown param field {key}")));
+            };
+
+            let value = map.next_value_seed(ValueSeed)?;
+            write_field(field, self.bytes, value).map_err(de::Error::custom)?;
+        }
+        Ok(())
+    }
+}
+
+/// A value of any shape, before it's been converted to a field's type.
+#[derive(Debug)]
+enum Value {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Seq(Vec<Value>),
+}
+
+impl Value {
+    /// Converts this to a scalar of type [ty], or returns `None` if it
+    /// doesn't fit.
+    fn to_scalar(&self, ty: ParamFieldType) -> Option<ParamFieldValue> {
+        let value = match *self {
+            Self::Bool(value) => value.into(),
+            Self::Int(value) => value as f64,
+            Self::Float(value) => value,
+            Self::Str(_) | Self::Seq(_) => return None,
+        };
+
+        fn int<T: TryFrom<i64>>(value: f64) -> Option<T> {
+            if value.fract() != 0.0 {
+                return None;
+            }
+            T::try_from(value as i64).ok()
+        }
+
+        Some(match ty {
+            ParamFieldType::U8 | ParamFieldType::Dummy8 | ParamFieldType::Fixstr => {
+                ParamFieldValue::U8(int(value)?)
+            }
+            ParamFieldType::S8 => ParamFieldValue::S8(int(value)?),
+            ParamFieldType::U16 | ParamFieldType::FixstrW => ParamFieldValue::U16(int(value)?),
+            ParamFieldType::S16 => ParamFieldValue::S16(int(value)?),
+            ParamFieldType::U32 => ParamFieldValue::U32(int(value)?),
+            ParamFieldType::S32 => ParamFieldValue::S32(int(value)?),
+            ParamFieldType::F32 => ParamFieldValue::F32(value as f32),
+        })
+    }
+}
+
+/// Writes [value] to [field] in [row], converting it to the field's type.
+fn write_field(field: &ParamFieldInfo, row: &mut [u8], value: Value) -> Result<(), String> {
+    let invalid = |value: &Value| format!("field {} can't hold {value:?}", field.name);
+
+    if let (Some(len), Value::Str(string)) = (field.array_len, &value) {
+        let bytes = &mut row[field.offset..field.offset + field.size()];
+        return match field.field_type {
+            ParamFieldType::Fixstr => encode_fixstr(string, bytes).map_err(|e| e.to_string()),
+            ParamFieldType::FixstrW => {
+                let mut units = vec![0; len];
+                encode_fixstr_w(string, &mut units);
+                for (unit, dest) in units.iter().zip(bytes.chunks_exact_mut(2)) {
+                    dest.copy_from_slice(&unit.to_le_bytes());
+                }
+                Ok(())
+            }
+            _ => Err(invalid(&value)),
+        };
+    }
+
+    let converted = match (&value, field.array_len) {
+        (Value::Seq(elements), Some(_)) => ParamFieldValue::Array(
+            elements
+                .iter()
+                .map(|element| {
+                    element
+                        .to_scalar(field.field_type)
+                        .ok_or_else(|| invalid(element))
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        (_, None) => value
+            .to_scalar(field.field_type)
+            .ok_or_else(|| invalid(&value))?,
+        _ => return Err(invalid(&value)),
+    };
+    field
+        .write(row, &converted)
+        .map_err(|error| error.to_string())
+}
+
+/// Deserializes a [Value] from any self-describing format.
+struct ValueSeed;
+
+impl<'de> DeserializeSeed<'de> for ValueSeed {
+    type Value = Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a bool, number, string, or sequence of numbers")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Value, E> {
+        Ok(Value::Int(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Value, E> {
+        i64::try_from(value)
+            .map(Value::Int)
+            .map_err(|_| E::custom(format!("{value} is too large for any param field")))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Value, E> {
+        Ok(Value::Float(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::Str(value.to_string()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut elements = Vec::new();
+        while let Some(element) = seq.next_element_seed(ValueSeed)? {
+            elements.push(element);
+        }
+        Ok(Value::Seq(elements))
+    }
+}
+
+/// Reads the little-endian UTF-16 code units in [bytes].
+fn utf16_units(bytes: &[u8]) -> Vec<u16> {
+    bytes
+        .chunks_exact(2)
+        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FIELD: ParamFieldInfo = ParamFieldInfo {
+        name: "field",
+        rust_name: "field",
+        display_name: None,
+        alt_name: None,
+        field_type: ParamFieldType::U8,
+        offset: 0,
+        bit_offset: 0,
+        bit_width: None,
+        array_len: None,
+        minimum: None,
+        maximum: None,
+        hidden: false,
+    };
+
+    #[derive(Default)]
+    struct Row([u8; 12]);
+
+    impl ParamLayout for Row {
+        const NAME: &str = "ROW_ST";
+        const FIELDS: &[ParamFieldInfo] = &[
+            ParamFieldInfo {
+                name: "hp",
+                rust_name: "hp",
+                field_type: ParamFieldType::S16,
+                ..FIELD
+            },
+            ParamFieldInfo {
+                name: "isBoss",
+                rust_name: "is_boss",
+                offset: 2,
+                bit_width: Some(1),
+                ..FIELD
+            },
+            ParamFieldInfo {
+                name: "pad",
+                rust_name: "pad",
+                field_type: ParamFieldType::Dummy8,
+                offset: 3,
+                hidden: true,
+                ..FIELD
+            },
+            ParamFieldInfo {
+                name: "speed",
+                rust_name: "speed",
+                field_type: ParamFieldType::F32,
+                offset: 4,
+                ..FIELD
+            },
+            ParamFieldInfo {
+                name: "name",
+                rust_name: "name",
+                field_type: ParamFieldType::Fixstr,
+                offset: 8,
+                array_len: Some(2),
+                ..FIELD
+            },
+            ParamFieldInfo {
+                name: "lotIds",
+                rust_name: "lot_ids",
+                offset: 10,
+                array_len: Some(2),
+                ..FIELD
+            },
+        ];
+
+        fn as_bytes(&self) -> &[u8] {
+            &self.0
+        }
+
+        fn as_bytes_mut(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
+    }
+
+    impl Serialize for Row {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_row(self, serializer)
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for Row {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize_row(deserializer)
+        }
+    }
+
+    fn row() -> Row {
+        Row([0xfe, 0xff, 1, 0xaa, 0, 0, 0x80, 0x3f, b'h', b'i', 3, 4])
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&row()).unwrap(),
+            r#"{"hp":-2,"is_boss":true,"speed":1.0,"name":"hi","lot_ids":[3,4]}"#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = serde_json::to_string(&row()).unwrap();
+        let read: Row = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            read.0,
+            [0xfe, 0xff, 1, 0, 0, 0, 0x80, 0x3f, b'h', b'i', 3, 4]
+        );
+    }
+
+    #[test]
+    fn patch() {
+        let mut row = row();
+        patch_row(
+            &mut row,
+            &mut serde_json::Deserializer::from_str(
+                r#"{"isBoss":false,"name":"x","lot_ids":[5,6]}"#,
+            ),
+        )
+        .unwrap();
+        assert_eq!(
+            row.0,
+            [0xfe, 0xff, 0, 0xaa, 0, 0, 0x80, 0x3f, b'x', 0, 5, 6]
+        );
+    }
+
+    #[test]
+    fn patch_rejects_invalid_values() {
+        for json in [
+            r#"{"hp":40000}"#,
+            r#"{"hp":1.5}"#,
+            r#"{"lot_ids":[1]}"#,
+            r#"{"speed":"fast"}"#,
+            r#"{"pad":0}"#,
+            r#"{"unknown":0}"#,
+        ] {
+            let mut row = row();
+            assert!(
+                patch_row(&mut row, &mut serde_json::Deserializer::from_str(json)).is_err(),
+                "{json}"
+            );
+            assert_eq!(row.0, self::row().0, "{json}");
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use std::fmt;
+
+/// A paramdef version, as used by paramdex's `FirstVersion` and
+/// `RemovedVersion` attributes to describe when fields were added or removed.
+///
+/// These are regulation versions, such as `11210015`, rather than the
+/// per-paramdef version stored in each `.param` file's header. The version of
+/// a regulation file is stored in its binder header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParamVersion(pub u32);
+
+impl fmt::Display for ParamVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
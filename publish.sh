@@ -1,4 +1,5 @@
 #!/bin/bash
 cargo publish --allow-dirty --no-verify -p fromsoftware-shared
 cargo publish --allow-dirty --no-verify -p fromsoftware-shared-macros
+cargo publish --allow-dirty --no-verify -p param-generator
 cargo publish --allow-dirty --no-verify -p eldenring
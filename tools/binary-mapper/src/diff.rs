@@ -0,0 +1,396 @@
+//! Compares the captures in a profile across two versions of a binary.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+use pelite::pe64::Pe;
+use serde::Serialize;
+
+use crate::verify::{CaptureReport, ReportFormat, format_candidates, serialize_rvas};
+
+/// The number of bytes at the start of each virtual method that are compared
+/// to detect index shifts.
+const FINGERPRINT_LEN: usize = 32;
+
+/// The number of bytes in [FINGERPRINT_LEN] that have to match for a virtual
+/// method to count as the same one. This leaves room for a few relocated
+/// displacements.
+const FINGERPRINT_THRESHOLD: usize = FINGERPRINT_LEN * 3 / 4;
+
+/// How a single capture changed between two binaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Change {
+    /// The capture matched the same RVAs in both binaries.
+    Unchanged,
+    /// The capture matched different RVAs in the new binary.
+    Moved,
+    /// The capture matched in the old binary but not the new one.
+    Vanished,
+    /// The capture matched in the new binary but not the old one.
+    Appeared,
+    /// The capture went from matching one RVA to several, or vice versa.
+    Ambiguity,
+}
+
+impl Change {
+    /// Returns the name of this change as it appears in reports.
+    fn name(self) -> &'static str {
+        match self {
+            Change::Unchanged => "unchanged",
+            Change::Moved => "moved",
+            Change::Vanished => "vanished",
+            Change::Appeared => "appeared",
+            Change::Ambiguity => "ambiguity",
+        }
+    }
+}
+
+/// A virtual method that seems to have moved to a different index in its VMT.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct IndexShift {
+    /// The index in the profile, which the method had in the old binary.
+    pub old: u32,
+
+    /// The index of the most similar method in the new binary.
+    pub new: u32,
+}
+
+/// A VMT that has a different number of virtual methods in the new binary,
+/// which usually means methods were added or removed.
+#[derive(Debug, Serialize)]
+pub struct VtableDiff {
+    pub class: String,
+    pub old_len: usize,
+    pub new_len: usize,
+}
+
+/// How a single capture changed between two binaries.
+#[derive(Debug, Serialize)]
+pub struct CaptureDiff {
+    pub name: String,
+    pub change: Change,
+
+    #[serde(serialize_with = "serialize_rvas")]
+    pub old: Vec<u32>,
+
+    #[serde(serialize_with = "serialize_rvas")]
+    pub new: Vec<u32>,
+
+    /// For VMT captures, where the virtual method seems to have moved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_shift: Option<IndexShift>,
+}
+
+impl CaptureDiff {
+    /// Returns whether anything about this capture changed.
+    fn is_changed(&self) -> bool {
+        self.change != Change::Unchanged || self.index_shift.is_some()
+    }
+}
+
+/// Every change to a profile's captures between two binaries.
+#[derive(Debug, Serialize)]
+pub struct ProfileDiff {
+    pub captures: Vec<CaptureDiff>,
+    pub vtables: Vec<VtableDiff>,
+}
+
+/// A JSON diff, which only includes the captures being reported.
+#[derive(Serialize)]
+struct JsonDiff<'a> {
+    captures: &'a [&'a CaptureDiff],
+    vtables: &'a [VtableDiff],
+}
+
+/// Compares the [old] and [new] reports for the same profile, matching
+/// captures up by name. The result is sorted by name.
+///
+/// A capture that's only in one of the reports is treated as having no
+/// candidates in the other.
+pub fn diff_reports(
+    old: Vec<CaptureReport>,
+    new: Vec<CaptureReport>,
+    mut shifts: HashMap<String, IndexShift>,
+    vtables: Vec<VtableDiff>,
+) -> ProfileDiff {
+    let mut candidates = BTreeMap::<_, (Vec<u32>, Vec<u32>)>::new();
+    for report in old {
+        candidates.entry(report.name).or_default().0 = report.candidates;
+    }
+    for report in new {
+        candidates.entry(report.name).or_default().1 = report.candidates;
+    }
+
+    let captures = candidates
+        .into_iter()
+        .map(|(name, (old, new))| CaptureDiff {
+            index_shift: shifts.remove(&name),
+            change: classify(&old, &new),
+            name,
+            old,
+            new,
+        })
+        .collect();
+
+    ProfileDiff { captures, vtables }
+}
+
+/// Returns how a capture changed from matching the [old] candidates to the
+/// [new] ones.
+fn classify(old: &[u32], new: &[u32]) -> Change {
+    if old == new {
+        Change::Unchanged
+    } else if new.is_empty() {
+        Change::Vanished
+    } else if old.is_empty() {
+        Change::Appeared
+    } else if (old.len() == 1) != (new.len() == 1) {
+        Change::Ambiguity
+    } else {
+        Change::Moved
+    }
+}
+
+/// Returns the index in [new_vtable] of the virtual method that most resembles
+/// the one at [index] in [old_vtable], if that's a different index.
+///
+/// Methods are compared by their first [FINGERPRINT_LEN] bytes. This is only a
+/// heuristic, so it only reports a shift when another method is a close match
+/// and a better one than the method that's now at [index].
+pub fn find_index_shift<'a, T: Pe<'a>>(
+    old: &T,
+    old_vtable: &[u32],
+    new: &T,
+    new_vtable: &[u32],
+    index: u32,
+) -> Option<u32> {
+    let old_bytes = fingerprint(old, *old_vtable.get(index as usize)?);
+    let new_bytes = new_vtable
+        .iter()
+        .map(|rva| fingerprint(new, *rva))
+        .collect::<Vec<_>>();
+    find_fingerprint_shift(old_bytes, &new_bytes, index)
+}
+
+/// Returns the index in [new] of the fingerprint that most resembles [old],
+/// if that's a close enough match and a better one than the fingerprint at
+/// [index]. See [find_index_shift].
+fn find_fingerprint_shift(old: &[u8], new: &[&[u8]], index: u32) -> Option<u32> {
+    let scores = new
+        .iter()
+        .map(|bytes| similarity(old, bytes))
+        .collect::<Vec<_>>();
+
+    let current = scores.get(index as usize).copied().unwrap_or_default();
+    let (best, score) = scores
+        .iter()
+        .copied()
+        .enumerate()
+        .max_by_key(|(_, score)| *score)?;
+    if score > current && score >= FINGERPRINT_THRESHOLD {
+        Some(best as u32)
+    } else {
+        None
+    }
+}
+
+/// Returns the first [FINGERPRINT_LEN] bytes of the function at [rva].
+fn fingerprint<'a, T: Pe<'a>>(program: &T, rva: u32) -> &'a [u8] {
+    program
+        .derva_slice(rva, FINGERPRINT_LEN)
+        .unwrap_or_default()
+}
+
+/// Returns the number of positions at which [a] and [b] have the same byte.
+fn similarity(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).filter(|(a, b)| a == b).count()
+}
+
+/// Formats [diff] as a human-readable table or as JSON. Unchanged captures
+/// are only included if [all] is true.
+pub fn format_diff(diff: &ProfileDiff, all: bool, format: ReportFormat) -> String {
+    let captures = diff
+        .captures
+        .iter()
+        .filter(|capture| all || capture.is_changed())
+        .collect::<Vec<_>>();
+
+    match format {
+        ReportFormat::Json => {
+            let mut output = serde_json::to_string_pretty(&JsonDiff {
+                captures: &captures,
+                vtables: &diff.vtables,
+            })
+            .unwrap();
+            output.push('\n');
+            output
+        }
+        ReportFormat::Table => {
+            let name_width = captures
+                .iter()
+                .map(|capture| capture.name.len())
+                .max()
+                .unwrap_or_default();
+
+            let mut output = String::new();
+            for capture in &captures {
+                writeln!(
+                    output,
+                    "{:<9}  {:<name_width$}  {} -> {}",
+                    capture.change.name(),
+                    capture.name,
+                    format_candidates(&capture.old),
+                    format_candidates(&capture.new)
+                )
+                .unwrap();
+                if let Some(shift) = capture.index_shift {
+                    writeln!(
+                        output,
+                        "{:<9}  vmt index {} -> {}",
+                        "", shift.old, shift.new
+                    )
+                    .unwrap();
+                }
+            }
+
+            if !diff.vtables.is_empty() {
+                output.push_str("\nResized vtables:\n");
+                for vtable in &diff.vtables {
+                    writeln!(
+                        output,
+                        "  {}: {} -> {} methods",
+                        vtable.class, vtable.old_len, vtable.new_len
+                    )
+                    .unwrap();
+                }
+            }
+
+            let count = |change| {
+                diff.captures
+                    .iter()
+                    .filter(|capture| capture.change == change)
+                    .count()
+            };
+            writeln!(
+                output,
+                "\n{} captures: {} moved, {} vanished, {} appeared, {} changed ambiguity, {} unchanged, {} shifted in their vtable",
+                diff.captures.len(),
+                count(Change::Moved),
+                count(Change::Vanished),
+                count(Change::Appeared),
+                count(Change::Ambiguity),
+                count(Change::Unchanged),
+                diff.captures
+                    .iter()
+                    .filter(|capture| capture.index_shift.is_some())
+                    .count()
+            )
+            .unwrap();
+            output
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn report(name: &str, candidates: &[u32]) -> CaptureReport {
+        CaptureReport {
+            name: name.to_string(),
+            source: String::new(),
+            candidates: candidates.to_vec(),
+        }
+    }
+
+    /// Returns a fingerprint whose first [matching] bytes are the same as
+    /// `[0; FINGERPRINT_LEN]`'s and whose rest differ.
+    fn fingerprint_matching(matching: usize) -> Vec<u8> {
+        (0..FINGERPRINT_LEN)
+            .map(|i| if i < matching { 0 } else { 0xcc })
+            .collect()
+    }
+
+    #[test]
+    fn classifies_changes() {
+        assert_eq!(classify(&[0x10], &[0x10]), Change::Unchanged);
+        assert_eq!(classify(&[], &[]), Change::Unchanged);
+        assert_eq!(classify(&[0x10], &[0x20]), Change::Moved);
+        assert_eq!(classify(&[0x10, 0x20], &[0x30, 0x40]), Change::Moved);
+        assert_eq!(classify(&[0x10], &[]), Change::Vanished);
+        assert_eq!(classify(&[], &[0x10]), Change::Appeared);
+        assert_eq!(classify(&[0x10], &[0x10, 0x20]), Change::Ambiguity);
+        assert_eq!(classify(&[0x10, 0x20], &[0x10]), Change::Ambiguity);
+    }
+
+    #[test]
+    fn diffs_reports_by_name() {
+        let old = vec![
+            report("b", &[0x20]),
+            report("a", &[0x10]),
+            report("only_old", &[0x30]),
+        ];
+        let new = vec![
+            report("a", &[0x10]),
+            report("only_new", &[0x40]),
+            report("b", &[0x28]),
+        ];
+        let shifts = HashMap::from([("b".to_string(), IndexShift { old: 1, new: 2 })]);
+
+        let diff = diff_reports(old, new, shifts, Vec::new());
+        let changes = diff
+            .captures
+            .iter()
+            .map(|capture| (capture.name.as_str(), capture.change))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            [
+                ("a", Change::Unchanged),
+                ("b", Change::Moved),
+                ("only_new", Change::Appeared),
+                ("only_old", Change::Vanished),
+            ]
+        );
+        assert_eq!(diff.captures[1].old, [0x20]);
+        assert_eq!(diff.captures[1].new, [0x28]);
+        assert!(diff.captures[1].index_shift.is_some());
+        assert!(diff.captures[0].index_shift.is_none());
+    }
+
+    #[test]
+    fn finds_shifted_method() {
+        let old = fingerprint_matching(FINGERPRINT_LEN);
+        let new = [
+            fingerprint_matching(0),
+            fingerprint_matching(4),
+            fingerprint_matching(30),
+        ];
+        let new = new.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        assert_eq!(find_fingerprint_shift(&old, &new, 1), Some(2));
+        assert_eq!(find_fingerprint_shift(&old, &new, 2), None);
+
+        // Indexes past the end of the new table can still find a shift.
+        assert_eq!(find_fingerprint_shift(&old, &new, 5), Some(2));
+    }
+
+    #[test]
+    fn ignores_weak_matches() {
+        let old = fingerprint_matching(FINGERPRINT_LEN);
+
+        // The best match is better than the current method, but not close
+        // enough to count.
+        let new = [
+            fingerprint_matching(0),
+            fingerprint_matching(FINGERPRINT_THRESHOLD - 1),
+        ];
+        let new = new.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        assert_eq!(find_fingerprint_shift(&old, &new, 0), None);
+
+        // The method at the index is as good a match as any other.
+        let new = [fingerprint_matching(28), fingerprint_matching(28)];
+        let new = new.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        assert_eq!(find_fingerprint_shift(&old, &new, 0), None);
+    }
+}
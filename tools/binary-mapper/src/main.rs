@@ -1,11 +1,11 @@
 use std::fmt::Write;
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 use std::{collections::HashMap, fs, fs::File};
 
 use clap::{Args, Parser, ValueEnum};
 use fromsoftware_shared::{Class, find_rtti_classes};
-use memmap::MmapOptions;
+use memmap::{Mmap, MmapOptions};
 use pelite::{
     pattern,
     pe64::{Pe, PeFile},
@@ -13,17 +13,33 @@ use pelite::{
 use rayon::prelude::*;
 use serde::Deserialize;
 
+mod diff;
+mod output;
+mod sigmake;
+mod verify;
+mod xref;
+
+use diff::{IndexShift, VtableDiff};
+use output::ExeVersion;
+use verify::{CaptureReport, ReportFormat};
+
 #[derive(ValueEnum, Clone)]
 enum OutputFormat {
     Print,
     RustStruct,
     Rust,
+    Json,
+    CHeader,
+    Csv,
 }
 
 /// Finds RVAs within a binary and emits them as code.
 #[derive(Parser)]
 enum BinaryMapper {
     Map(MapArgs),
+    Verify(VerifyArgs),
+    Diff(DiffArgs),
+    Sigmake(SigmakeArgs),
     #[command(name = "er")]
     EldenRing(EldenRingArgs),
     #[command(name = "ds3")]
@@ -43,6 +59,64 @@ struct MapArgs {
     output: OutputFormat,
 }
 
+/// Checks that every capture in a profile matches exactly once in an EXE, and
+/// exits with an error if any don't.
+#[derive(Args)]
+struct VerifyArgs {
+    #[arg(long, env("MAPPER_PROFILE"))]
+    profile: PathBuf,
+
+    #[arg(long, env("MAPPER_GAME_EXE"))]
+    exe: PathBuf,
+
+    #[arg(long, env("MAPPER_REPORT_FORMAT"), default_value = "table")]
+    format: ReportFormat,
+}
+
+/// Maps two versions of an EXE and reports how each capture changed between
+/// them.
+#[derive(Args)]
+struct DiffArgs {
+    #[arg(long, env("MAPPER_PROFILE"))]
+    profile: PathBuf,
+
+    /// The EXE from before the patch.
+    #[arg(long)]
+    old: PathBuf,
+
+    /// The EXE from after the patch.
+    #[arg(long)]
+    new: PathBuf,
+
+    /// Whether to include captures that didn't change at all.
+    #[arg(long)]
+    all: bool,
+
+    #[arg(long, env("MAPPER_REPORT_FORMAT"), default_value = "table")]
+    format: ReportFormat,
+}
+
+/// Generates a unique pattern for a function or call site and prints it as a
+/// profile entry.
+#[derive(Args)]
+struct SigmakeArgs {
+    #[arg(long, env("MAPPER_GAME_EXE"))]
+    exe: PathBuf,
+
+    /// The RVA to generate a pattern for, such as `0x1a2b3c`.
+    #[arg(long, value_parser = parse_rva)]
+    rva: u32,
+
+    /// The name of the capture in the profile entry.
+    #[arg(long)]
+    name: String,
+
+    /// Treats the RVA as a `call` or `jmp` instruction, and captures the
+    /// function it targets.
+    #[arg(long)]
+    target: bool,
+}
+
 /// Shortcut to map all files for Elden Ring.
 #[derive(Args)]
 struct EldenRingArgs {
@@ -80,22 +154,101 @@ fn main() {
                 return;
             }
 
-            let results = map_results(
-                &profile,
-                &args.exe.unwrap_or_else(|| {
-                    panic!(
-                        "exe must be passed with --output {}",
-                        args.output.to_possible_value().unwrap().get_name()
-                    )
-                }),
-            );
+            let exe = args.exe.unwrap_or_else(|| {
+                panic!(
+                    "exe must be passed with --output {}",
+                    args.output.to_possible_value().unwrap().get_name()
+                )
+            });
+            let results = map_results(&profile, &exe);
+            let version = || {
+                let exe_mmap = map_exe(&exe);
+                let program = PeFile::from_bytes(&exe_mmap[0..])
+                    .expect("Could not create PE view for game binary");
+                ExeVersion::read(program)
+            };
 
             match args.output {
                 OutputFormat::Print => println!("Results: {results:#x?}"),
                 OutputFormat::Rust => println!("{}", generate_rust_instance(&results)),
+                OutputFormat::Json => println!("{}", output::generate_json(&version(), &results)),
+                OutputFormat::CHeader => {
+                    print!("{}", output::generate_c_header(&version(), &results))
+                }
+                OutputFormat::Csv => print!("{}", output::generate_csv(&version(), &results)),
                 OutputFormat::RustStruct => { /* handled above */ }
             }
         }
+        BinaryMapper::Verify(args) => {
+            let profile = read_profile(args.profile);
+            let reports = scan_results(&profile, &args.exe);
+            print!("{}", verify::format_reports(&reports, args.format));
+            if reports.iter().any(|report| !report.status().is_ok()) {
+                process::exit(1);
+            }
+        }
+        BinaryMapper::Diff(args) => {
+            let profile = read_profile(args.profile);
+            let old_mmap = map_exe(&args.old);
+            let old = PeFile::from_bytes(&old_mmap[0..])
+                .expect("Could not create PE view for old game binary");
+            let new_mmap = map_exe(&args.new);
+            let new = PeFile::from_bytes(&new_mmap[0..])
+                .expect("Could not create PE view for new game binary");
+            let old_rtti = rtti_classes(&old);
+            let new_rtti = rtti_classes(&new);
+
+            let mut shifts = HashMap::new();
+            let mut vtables = Vec::new();
+            for entry in &profile.vmts {
+                let (Some(old_vtable), Some(new_vtable)) =
+                    (entry.vtable(&old, &old_rtti), entry.vtable(&new, &new_rtti))
+                else {
+                    continue;
+                };
+
+                if old_vtable.len() != new_vtable.len() {
+                    vtables.push(VtableDiff {
+                        class: entry.class.clone(),
+                        old_len: old_vtable.len(),
+                        new_len: new_vtable.len(),
+                    });
+                }
+                for (name, index) in &entry.captures {
+                    if let Some(new_index) =
+                        diff::find_index_shift(&old, &old_vtable, &new, &new_vtable, *index)
+                    {
+                        shifts.insert(
+                            name.clone(),
+                            IndexShift {
+                                old: *index,
+                                new: new_index,
+                            },
+                        );
+                    }
+                }
+            }
+
+            let diff = diff::diff_reports(
+                scan_program(&profile, &old, &old_rtti),
+                scan_program(&profile, &new, &new_rtti),
+                shifts,
+                vtables,
+            );
+            print!("{}", diff::format_diff(&diff, args.all, args.format));
+        }
+        BinaryMapper::Sigmake(args) => {
+            let exe_mmap = map_exe(&args.exe);
+            let program = PeFile::from_bytes(&exe_mmap[0..])
+                .expect("Could not create PE view for game binary");
+            match sigmake::make_signature(program, args.rva, &args.name, args.target) {
+                Ok(signature) => print!("{}", signature.to_toml()),
+                Err(error) => {
+                    eprintln!("{error}");
+                    process::exit(1);
+                }
+            }
+        }
         BinaryMapper::EldenRing(args) => {
             let er = args
                 .project_root
@@ -104,17 +257,11 @@ fn main() {
                 })
                 .unwrap_or_else(|| game_crate_path("eldenring"));
             let profile = read_profile(er.join("mapper-profile.toml"));
+            let ww = map_verified_results(&profile, &args.ww_exe);
+            let jp = map_verified_results(&profile, &args.jp_exe);
             fs::write(er.join("src/rva/bundle.rs"), generate_rust_struct(&profile)).unwrap();
-            fs::write(
-                er.join("src/rva/rva_ww.rs"),
-                generate_rust_instance(&map_results(&profile, &args.ww_exe)),
-            )
-            .unwrap();
-            fs::write(
-                er.join("src/rva/rva_jp.rs"),
-                generate_rust_instance(&map_results(&profile, &args.jp_exe)),
-            )
-            .unwrap();
+            fs::write(er.join("src/rva/rva_ww.rs"), generate_rust_instance(&ww)).unwrap();
+            fs::write(er.join("src/rva/rva_jp.rs"), generate_rust_instance(&jp)).unwrap();
             cargo_fmt(&er);
         }
         BinaryMapper::DarkSoulsIII(args) => {
@@ -125,6 +272,7 @@ fn main() {
                 })
                 .unwrap_or_else(|| game_crate_path("darksouls3"));
             let profile = read_profile(ds3.join("mapper-profile.toml"));
+            let results = map_verified_results(&profile, &args.exe);
             fs::write(
                 ds3.join("src/rva/bundle.rs"),
                 generate_rust_struct(&profile),
@@ -132,7 +280,7 @@ fn main() {
             .unwrap();
             fs::write(
                 ds3.join("src/rva/rva_data.rs"),
-                generate_rust_instance(&map_results(&profile, &args.exe)),
+                generate_rust_instance(&results),
             )
             .unwrap();
             cargo_fmt(&ds3);
@@ -140,6 +288,16 @@ fn main() {
     }
 }
 
+/// Parses an RVA passed on the command line, in hex with or without a `0x`
+/// prefix.
+fn parse_rva(value: &str) -> Result<u32, String> {
+    let digits = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .unwrap_or(value);
+    u32::from_str_radix(digits, 16).map_err(|error| error.to_string())
+}
+
 /// Reads a mapper profile from disk at [path].
 fn read_profile<P: AsRef<Path>>(path: P) -> MapperProfile {
     let contents = fs::read_to_string(path).expect("Could not read profile file");
@@ -176,16 +334,28 @@ fn cargo_fmt<P: AsRef<Path>>(path: P) {
         .unwrap();
 }
 
+/// Memory-maps the binary at [exe].
+fn map_exe(exe: &Path) -> Mmap {
+    let exe_file = File::open(exe).expect("Could not open game binary");
+    unsafe { MmapOptions::new().map(&exe_file) }.expect("Could not mmap game binary")
+}
+
+/// Returns every RTTI class in [program], by name. Some names, such as those
+/// of classes with multiple bases, have more than one virtual method table.
+fn rtti_classes<'a, T: Pe<'a>>(program: &'a T) -> HashMap<String, Vec<Class<'a, T>>> {
+    let mut classes = HashMap::<_, Vec<_>>::new();
+    for class in find_rtti_classes(program) {
+        classes.entry(class.name.clone()).or_default().push(class);
+    }
+    classes
+}
+
 /// Loads the results for [profile] from the binary at [exe].
 fn map_results(profile: &MapperProfile, exe: &Path) -> Vec<MapperEntryResult> {
-    let exe_file = File::open(exe).expect("Could not open game binary");
-    let exe_mmap =
-        unsafe { MmapOptions::new().map(&exe_file) }.expect("Could not mmap game binary");
+    let exe_mmap = map_exe(exe);
     let program =
         PeFile::from_bytes(&exe_mmap[0..]).expect("Could not create PE view for game binary");
-    let rtti_map = find_rtti_classes(&program)
-        .map(|class| (class.name.clone(), class))
-        .collect::<HashMap<_, _>>();
+    let rtti_map = rtti_classes(&program);
 
     let mut results = profile
         .patterns
@@ -197,11 +367,71 @@ fn map_results(profile: &MapperProfile, exe: &Path) -> Vec<MapperEntryResult> {
                 .par_iter()
                 .flat_map(|entry| entry.find(&program, &rtti_map)),
         )
+        .chain(profile.xrefs.par_iter().map(|entry| entry.find(program)))
         .collect::<Vec<_>>();
     results.sort_by(|r1, r2| r1.name.cmp(&r2.name));
     results
 }
 
+/// Loads the results for [profile] from the binary at [exe] for the shortcut
+/// commands.
+///
+/// Unlike [map_results], this checks every capture like the `verify` command
+/// does, and exits with an error if any of them are missing or ambiguous, so
+/// that the shortcuts never write RVAs that might be wrong.
+fn map_verified_results(profile: &MapperProfile, exe: &Path) -> Vec<MapperEntryResult> {
+    let (ok, failed): (Vec<_>, Vec<_>) = scan_results(profile, exe)
+        .into_iter()
+        .partition(|report| report.status().is_ok());
+    if !failed.is_empty() {
+        eprintln!(
+            "Not writing any RVAs, since some captures don't match {} exactly once:",
+            exe.display()
+        );
+        eprint!("{}", verify::format_reports(&failed, ReportFormat::Table));
+        process::exit(1);
+    }
+
+    ok.into_iter()
+        .map(|report| MapperEntryResult {
+            name: report.name,
+            rva: Some(report.candidates[0]),
+        })
+        .collect()
+}
+
+/// Loads every candidate for each capture in [profile] from the binary at
+/// [exe].
+fn scan_results(profile: &MapperProfile, exe: &Path) -> Vec<CaptureReport> {
+    let exe_mmap = map_exe(exe);
+    let program =
+        PeFile::from_bytes(&exe_mmap[0..]).expect("Could not create PE view for game binary");
+    scan_program(profile, &program, &rtti_classes(&program))
+}
+
+/// Loads every candidate for each capture in [profile] from [program], whose
+/// RTTI classes are [rtti_map].
+fn scan_program<'a>(
+    profile: &MapperProfile,
+    program: &PeFile<'a>,
+    rtti_map: &HashMap<String, Vec<Class<'a, PeFile<'a>>>>,
+) -> Vec<CaptureReport> {
+    let mut reports = profile
+        .patterns
+        .par_iter()
+        .flat_map(|entry| entry.scan(program))
+        .chain(
+            profile
+                .vmts
+                .par_iter()
+                .flat_map(|entry| entry.scan(program, rtti_map)),
+        )
+        .chain(profile.xrefs.par_iter().map(|entry| entry.scan(*program)))
+        .collect::<Vec<_>>();
+    reports.sort_by(|r1, r2| r1.name.cmp(&r2.name));
+    reports
+}
+
 /// Generates a Rust struct with fields for each RVA lsited in the given
 /// [profile].
 fn generate_rust_struct(profile: &MapperProfile) -> String {
@@ -230,6 +460,7 @@ fn generate_rust_struct(profile: &MapperProfile) -> String {
                 .iter()
                 .flat_map(|entry| entry.captures.keys().chain(entry.vftable.iter())),
         )
+        .chain(profile.xrefs.iter().map(|entry| &entry.capture))
         .collect::<Vec<_>>();
     fields.sort();
     for field in fields {
@@ -242,6 +473,9 @@ fn generate_rust_struct(profile: &MapperProfile) -> String {
 
 /// Generates a file that declares an instance of `RvaBundle` with the given
 /// [results].
+///
+/// Captures that weren't found become `compile_error!`s, so that the file
+/// can't be used until they're fixed.
 fn generate_rust_instance(results: &[MapperEntryResult]) -> String {
     let mut output = String::from(
         "//! Generated RVA mappings for a single executable.\n\
@@ -257,7 +491,15 @@ fn generate_rust_instance(results: &[MapperEntryResult]) -> String {
                  pub const RVAS: RvaBundle = RvaBundle {\n",
     );
     for result in results {
-        writeln!(output, "{}: {:#x},", result.name, result.rva).unwrap();
+        match result.rva {
+            Some(rva) => writeln!(output, "{}: {rva:#x},", result.name),
+            None => writeln!(
+                output,
+                "{0}: compile_error!(\"{0} wasn't found\"),",
+                result.name
+            ),
+        }
+        .unwrap();
     }
     output.push_str("};");
     output
@@ -270,6 +512,8 @@ struct MapperProfile {
     pub patterns: Vec<MapperProfilePattern>,
     #[serde(default)]
     pub vmts: Vec<MapperProfileVmt>,
+    #[serde(default)]
+    pub xrefs: Vec<MapperProfileXref>,
 }
 
 /// A Pelite pattern which matches one or more offsets.
@@ -288,33 +532,43 @@ struct MapperProfilePattern {
 impl MapperProfilePattern {
     /// Consumes self and looks up the pattern in [program].
     fn find<'a>(&self, program: &impl Pe<'a>) -> Vec<MapperEntryResult> {
+        self.scan(program)
+            .into_iter()
+            .map(|report| MapperEntryResult {
+                rva: report.candidates.first().copied(),
+                name: report.name,
+            })
+            .collect::<Vec<_>>()
+    }
+
+    /// Finds every match of the pattern in [program] and reports the distinct
+    /// RVAs of each capture, in the order they were found.
+    fn scan<'a>(&self, program: &impl Pe<'a>) -> Vec<CaptureReport> {
         let Ok(scanner_pattern) = pattern::parse(&self.pattern) else {
             panic!("Could not parse provided pattern \"{}\"", &self.pattern)
         };
 
-        let mut matches = vec![0u32; self.captures.len()];
-        let captures = self
-            .captures
-            .iter()
-            .enumerate()
-            .filter(|(_, e)| !e.is_empty());
-
-        if !program
-            .scanner()
-            .matches_code(&scanner_pattern)
-            .next(&mut matches)
-        {
-            captures
-                .map(|(_, e)| MapperEntryResult::not_found(e))
-                .collect::<Vec<_>>()
-        } else {
-            captures
-                .map(|(i, e)| MapperEntryResult {
-                    name: e.clone(),
-                    rva: matches[i],
-                })
-                .collect::<Vec<_>>()
+        let mut save = vec![0u32; self.captures.len()];
+        let mut candidates = vec![Vec::new(); self.captures.len()];
+        let mut matches = program.scanner().matches_code(&scanner_pattern);
+        while matches.next(&mut save) {
+            for (rvas, rva) in candidates.iter_mut().zip(&save) {
+                if !rvas.contains(rva) {
+                    rvas.push(*rva);
+                }
+            }
         }
+
+        self.captures
+            .iter()
+            .zip(candidates)
+            .filter(|(name, _)| !name.is_empty())
+            .map(|(name, candidates)| CaptureReport {
+                name: name.clone(),
+                source: format!("pattern \"{}\"", self.pattern),
+                candidates,
+            })
+            .collect::<Vec<_>>()
     }
 }
 
@@ -335,51 +589,190 @@ struct MapperProfileVmt {
 
 impl MapperProfileVmt {
     /// Consumes self and looks up the VMT in [rtti_map].
+    ///
+    /// If several classes share the name, this uses the last one found.
     fn find<'a, T: Pe<'a>>(
         &self,
         program: &T,
-        rtti_map: &HashMap<String, Class<'a, T>>,
+        rtti_map: &HashMap<String, Vec<Class<'a, T>>>,
     ) -> Vec<MapperEntryResult> {
-        let Some(class) = rtti_map.get(self.class.as_str()) else {
+        let Some(class) = rtti_map
+            .get(self.class.as_str())
+            .and_then(|classes| classes.last())
+        else {
             panic!("No RTTI class named {}", self.class);
         };
 
+        let vtable = vmt_fn_rvas(program, class);
         self.captures
             .iter()
-            .map(|(name, index)| {
-                // Safety: We're not actually dereferencing the VA.
-                if let Some(va) = unsafe { class.vmt_fn(*index) }
-                    && let Ok(rva) = program.va_to_rva(va)
-                {
-                    return MapperEntryResult {
-                        name: name.clone(),
-                        rva,
-                    };
-                }
-
-                MapperEntryResult::not_found(name)
+            .map(|(name, index)| MapperEntryResult {
+                name: name.clone(),
+                rva: vtable.get(*index as usize).copied(),
             })
             .chain(self.vftable.iter().map(|name| MapperEntryResult {
                 name: name.clone(),
-                rva: class.vftable,
+                rva: Some(class.vftable),
+            }))
+            .collect::<Vec<_>>()
+    }
+
+    /// Returns the RVAs of every virtual method in the VMT, or `None` if
+    /// [rtti_map] doesn't have the class.
+    ///
+    /// Like [find], this uses the last class found with the name.
+    fn vtable<'a, T: Pe<'a>>(
+        &self,
+        program: &T,
+        rtti_map: &HashMap<String, Vec<Class<'a, T>>>,
+    ) -> Option<Vec<u32>> {
+        let class = rtti_map.get(self.class.as_str())?.last()?;
+        Some(vmt_fn_rvas(program, class))
+    }
+
+    /// Looks up the VMT in [rtti_map] and reports the distinct RVAs of each
+    /// capture across every class with the name.
+    ///
+    /// Indexes past the end of a class's VMT don't match anything in that
+    /// class, so a capture whose index is beyond every VMT is missing.
+    fn scan<'a, T: Pe<'a>>(
+        &self,
+        program: &T,
+        rtti_map: &HashMap<String, Vec<Class<'a, T>>>,
+    ) -> Vec<CaptureReport> {
+        let vtables = rtti_map
+            .get(self.class.as_str())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(|class| (class.vftable, vmt_fn_rvas(program, class)))
+            .collect::<Vec<_>>();
+
+        self.captures
+            .iter()
+            .map(|(name, index)| CaptureReport {
+                name: name.clone(),
+                source: format!("vmt {}[{index}]", self.class),
+                candidates: distinct(
+                    vtables
+                        .iter()
+                        .filter_map(|(_, vtable)| vtable.get(*index as usize).copied()),
+                ),
+            })
+            .chain(self.vftable.iter().map(|name| CaptureReport {
+                name: name.clone(),
+                source: format!("vftable {}", self.class),
+                candidates: distinct(vtables.iter().map(|(vftable, _)| *vftable)),
             }))
             .collect::<Vec<_>>()
     }
 }
 
+/// A string literal whose references in code identify a function.
+#[derive(Debug, Deserialize)]
+struct MapperProfileXref {
+    /// The contents of the string, without its null terminator.
+    string: String,
+
+    /// Whether the string is UTF-16, like `L"..."` literals in C++.
+    #[serde(default)]
+    wide: bool,
+
+    /// The name for the capture.
+    capture: String,
+
+    /// If this is set, the capture is the target of this `call` instruction
+    /// after the reference, counting from 0, or the pointer it calls through.
+    /// Otherwise, it's the start of the function that contains the reference.
+    call: Option<u32>,
+}
+
+impl MapperProfileXref {
+    /// Consumes self and looks up the string's references in [program].
+    fn find(&self, program: PeFile<'_>) -> MapperEntryResult {
+        let report = self.scan(program);
+        MapperEntryResult {
+            rva: report.candidates.first().copied(),
+            name: report.name,
+        }
+    }
+
+    /// Finds every reference to the string in [program] and reports the
+    /// distinct RVAs they lead to, in the order they were found.
+    fn scan(&self, program: PeFile<'_>) -> CaptureReport {
+        let text = program
+            .section_headers()
+            .by_name(".text")
+            .expect("no .text section found")
+            .virtual_range();
+        let strings = xref::find_strings(program, &self.string, self.wide);
+        let references = xref::find_references(program, &text, &strings);
+
+        CaptureReport {
+            name: self.capture.clone(),
+            source: match self.call {
+                Some(call) => format!("call {call} after xref {:?}", self.string),
+                None => format!("xref {:?}", self.string),
+            },
+            candidates: distinct(references.into_iter().filter_map(|rva| match self.call {
+                Some(call) => xref::nth_call_target(program, &text, rva, call),
+                None => xref::function_start(program, rva),
+            })),
+        }
+    }
+}
+
+/// Returns the RVAs of every virtual method in [class]'s VMT.
+///
+/// The VMT ends at the first entry that isn't a function in `.text`, which is
+/// usually the RTTI locator for the next table.
+fn vmt_fn_rvas<'a, T: Pe<'a>>(program: &T, class: &Class<'a, T>) -> Vec<u32> {
+    let Some(text) = program.section_headers().by_name(".text") else {
+        return Vec::new();
+    };
+    let text = text.virtual_range();
+
+    (0..)
+        .map_while(|index| {
+            // Safety: We're not actually dereferencing the VA.
+            let va = unsafe { class.vmt_fn(index) }?;
+            program.va_to_rva(va).ok().filter(|rva| text.contains(rva))
+        })
+        .collect()
+}
+
+/// Collects [rvas] without duplicates, in the order they first appear.
+fn distinct(rvas: impl Iterator<Item = u32>) -> Vec<u32> {
+    let mut result = Vec::new();
+    for rva in rvas {
+        if !result.contains(&rva) {
+            result.push(rva);
+        }
+    }
+    result
+}
+
 /// Result of one of the entry items.
 #[derive(Debug, Deserialize)]
 struct MapperEntryResult {
     pub name: String,
-    pub rva: u32,
+
+    /// The capture's RVA, or `None` if it wasn't found.
+    pub rva: Option<u32>,
 }
 
-impl MapperEntryResult {
-    /// Returns a result indicating that the capture named [name] wasn't found.
-    fn not_found(name: impl AsRef<str>) -> Self {
-        MapperEntryResult {
-            name: name.as_ref().to_string(),
-            rva: 0x0,
-        }
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_hex_rvas() {
+        assert_eq!(parse_rva("0x1a2b"), Ok(0x1a2b));
+        assert_eq!(parse_rva("0X1A2B"), Ok(0x1a2b));
+        assert_eq!(parse_rva("1a2b"), Ok(0x1a2b));
+        assert!(parse_rva("").is_err());
+        assert!(parse_rva("0x").is_err());
+        assert!(parse_rva("0x1g").is_err());
+        assert!(parse_rva("0x100000000").is_err());
     }
 }
@@ -0,0 +1,192 @@
+//! Output formats for mapped RVAs that other languages and tools can read.
+
+use std::fmt::Write;
+
+use pelite::pe64::{Pe, PeFile};
+use serde::Serialize;
+
+use crate::MapperEntryResult;
+
+/// Metadata about an executable from its PE version resource. These are the
+/// same values the game crates use to detect which RVAs to load.
+#[derive(Debug, Default, Serialize)]
+pub struct ExeVersion {
+    /// The `ProductName` string, such as `ELDEN RING™`.
+    pub product: Option<String>,
+
+    /// The product version, such as `2.6.1.0`.
+    pub version: Option<String>,
+
+    /// The primary language ID of the version resource, such as `0x0009` for
+    /// English.
+    pub language: Option<u16>,
+}
+
+impl ExeVersion {
+    /// Reads the version metadata from [program]. Any values that can't be
+    /// found are left as `None`.
+    pub fn read(program: PeFile<'_>) -> Self {
+        let Some(info) = program
+            .resources()
+            .ok()
+            .and_then(|resources| resources.version_info().ok())
+        else {
+            return Self::default();
+        };
+
+        let version = info.fixed().map(|fixed| {
+            let version = fixed.dwProductVersion;
+            format!(
+                "{}.{}.{}.{}",
+                version.Major, version.Minor, version.Patch, version.Build
+            )
+        });
+
+        let language = info.translation().first().copied();
+        let product = language.and_then(|language| info.value(language, "ProductName"));
+
+        ExeVersion {
+            product,
+            version,
+            language: language.map(|language| language.lang_id & 0x03FF),
+        }
+    }
+}
+
+/// A JSON file with the executable's version and its RVAs.
+#[derive(Serialize)]
+struct JsonOutput<'a> {
+    game: &'a ExeVersion,
+    rvas: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Generates a JSON object with the executable's [version] and an `rvas`
+/// object mapping each capture name in [results] to its RVA as a hex string,
+/// or `null` if it wasn't found.
+pub fn generate_json(version: &ExeVersion, results: &[MapperEntryResult]) -> String {
+    let output = JsonOutput {
+        game: version,
+        rvas: results
+            .iter()
+            .map(|result| {
+                let rva = result
+                    .rva
+                    .map_or(serde_json::Value::Null, |rva| format!("{rva:#x}").into());
+                (result.name.clone(), rva)
+            })
+            .collect(),
+    };
+    serde_json::to_string_pretty(&output).unwrap()
+}
+
+/// Generates a C/C++ header that defines the executable's [version] and an
+/// `RVA_` macro for each of [results]. Captures that weren't found are left
+/// undefined, with a comment in their place.
+pub fn generate_c_header(version: &ExeVersion, results: &[MapperEntryResult]) -> String {
+    let mut output = String::from(
+        "// Generated RVA mappings for a single executable.\n\
+         \n\
+         // DO NOT EDIT THIS FILE DIRECTLY.\n\
+         \n\
+         #pragma once\n\
+         \n",
+    );
+
+    writeln!(
+        output,
+        "#define RVA_GAME_PRODUCT {}",
+        c_string(version.product.as_deref())
+    )
+    .unwrap();
+    writeln!(
+        output,
+        "#define RVA_GAME_VERSION {}",
+        c_string(version.version.as_deref())
+    )
+    .unwrap();
+    if let Some(language) = version.language {
+        writeln!(output, "#define RVA_GAME_LANGUAGE {language:#06x}").unwrap();
+    }
+
+    output.push('\n');
+    for result in results {
+        let name = result.name.to_ascii_uppercase();
+        match result.rva {
+            Some(rva) => writeln!(output, "#define RVA_{name} {rva:#x}u"),
+            None => writeln!(output, "// RVA_{name} wasn't found."),
+        }
+        .unwrap();
+    }
+    output
+}
+
+/// Generates a CSV file with a row for each of [results]. Every row includes
+/// the executable's [version] as well, so the file can be read one row at a
+/// time. Captures that weren't found have an empty `rva`.
+pub fn generate_csv(version: &ExeVersion, results: &[MapperEntryResult]) -> String {
+    let product = csv_field(version.product.as_deref().unwrap_or_default());
+    let game_version = csv_field(version.version.as_deref().unwrap_or_default());
+    let language = version
+        .language
+        .map(|language| format!("{language:#06x}"))
+        .unwrap_or_default();
+
+    let mut output = String::from("name,rva,product,version,language\n");
+    for result in results {
+        let rva = result
+            .rva
+            .map(|rva| format!("{rva:#x}"))
+            .unwrap_or_default();
+        writeln!(
+            output,
+            "{},{rva},{product},{game_version},{language}",
+            csv_field(&result.name)
+        )
+        .unwrap();
+    }
+    output
+}
+
+/// Formats [value] as a C string literal, or an empty one if it's `None`.
+fn c_string(value: Option<&str>) -> String {
+    let mut literal = String::from("\"");
+    for c in value.unwrap_or_default().chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            _ => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Quotes [value] for a CSV file if it contains anything that needs it.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escapes_c_strings() {
+        assert_eq!(c_string(None), r#""""#);
+        assert_eq!(c_string(Some("1.16.0")), r#""1.16.0""#);
+        assert_eq!(c_string(Some("a \"b\"\\c\nd")), r#""a \"b\"\\c\nd""#);
+    }
+
+    #[test]
+    fn quotes_csv_fields() {
+        assert_eq!(csv_field("RVA_GLOBAL"), "RVA_GLOBAL");
+        assert_eq!(csv_field("a,b"), r#""a,b""#);
+        assert_eq!(csv_field(r#"say "hi""#), r#""say ""hi""""#);
+        assert_eq!(csv_field("a\r\nb"), "\"a\r\nb\"");
+    }
+}
@@ -0,0 +1,294 @@
+//! Generates unique patterns for addresses in a binary.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Range;
+
+use iced_x86::{Code, Decoder, DecoderOptions, Mnemonic, OpKind};
+use pelite::{
+    image::{IMAGE_REL_BASED_DIR64, IMAGE_REL_BASED_HIGHLOW},
+    pattern,
+    pe64::{Pe, PeFile},
+};
+
+use crate::xref::runtime_function;
+
+/// The maximum number of instructions to add to a pattern before giving up on
+/// making it unique.
+const MAX_INSTRUCTIONS: usize = 64;
+
+/// The minimum number of concrete bytes a pattern should have, so that
+/// patterns don't stop at something like a lone `e8` that just happens to be
+/// unique in this binary.
+const MIN_BYTES: usize = 5;
+
+/// A single element of a generated pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    /// A byte that has to match exactly.
+    Byte(u8),
+
+    /// A byte that can have any value, such as part of a relocated address.
+    Wildcard,
+
+    /// A 4-byte relative displacement. If `capture` is true, the pattern
+    /// captures its target.
+    Jump { capture: bool },
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Byte(byte) => write!(f, "{byte:02x}"),
+            Token::Wildcard => write!(f, "?"),
+            Token::Jump { capture: false } => write!(f, "$ {{}}"),
+            Token::Jump { capture: true } => write!(f, "$ {{ ' }}"),
+        }
+    }
+}
+
+/// A pattern that uniquely matches an address, along with its captures.
+#[derive(Debug)]
+pub struct Signature {
+    pub pattern: String,
+    pub captures: Vec<String>,
+}
+
+impl Signature {
+    /// Returns this signature as a `[[patterns]]` entry for a mapper profile.
+    pub fn to_toml(&self) -> String {
+        let captures = self
+            .captures
+            .iter()
+            .map(|capture| format!("{capture:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "[[patterns]]\npattern = {:?}\ncaptures = [{captures}]\n",
+            self.pattern
+        )
+    }
+}
+
+/// An error generating a signature.
+#[derive(Debug)]
+pub enum SigmakeError {
+    /// The RVA isn't in the binary's `.text` section.
+    NotCode(u32),
+
+    /// `--target` was passed, but the RVA isn't a `call` or `jmp` instruction
+    /// with a 4-byte displacement.
+    NotBranch(u32),
+
+    /// The instructions at the RVA couldn't be decoded.
+    InvalidInstruction(u32),
+
+    /// No unique pattern was found before the end of the function or running
+    /// out of instructions.
+    NotUnique(u32),
+}
+
+impl fmt::Display for SigmakeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SigmakeError::NotCode(rva) => write!(f, "{rva:#x} isn't in the .text section"),
+            SigmakeError::NotBranch(rva) => {
+                write!(f, "{rva:#x} isn't a call or jmp with a 4-byte displacement")
+            }
+            SigmakeError::InvalidInstruction(rva) => {
+                write!(f, "Couldn't decode the instruction at {rva:#x}")
+            }
+            SigmakeError::NotUnique(rva) => write!(
+                f,
+                "Couldn't find a unique pattern for {rva:#x} before the end of its function or \
+                 within {MAX_INSTRUCTIONS} instructions"
+            ),
+        }
+    }
+}
+
+/// Generates the shortest pattern starting at [rva] that matches nothing else
+/// in [program]'s `.text` section, and names its capture [name].
+///
+/// Relative displacements and relocated bytes are left out of the pattern,
+/// since those change whenever the code around them moves. If [target] is
+/// true, [rva] must be a `call` or `jmp`, and the pattern captures the
+/// function it targets rather than [rva] itself.
+///
+/// Patterns never extend past the end of the function, which is its `ret`,
+/// the `int3` padding after it, or its end according to the unwind data,
+/// since whatever follows it can move independently.
+pub fn make_signature(
+    program: PeFile<'_>,
+    rva: u32,
+    name: &str,
+    target: bool,
+) -> Result<Signature, SigmakeError> {
+    let text = program
+        .section_headers()
+        .by_name(".text")
+        .expect("no .text section found")
+        .virtual_range();
+    if !text.contains(&rva) {
+        return Err(SigmakeError::NotCode(rva));
+    }
+
+    let relocated = relocated_bytes(program, &text);
+    let end = runtime_function(program, rva).map_or(text.end, |function| function.EndAddress);
+    let code = program
+        .derva_slice::<u8>(rva, (end - rva) as usize)
+        .map_err(|_| SigmakeError::NotCode(rva))?;
+    let mut decoder = Decoder::with_ip(64, code, rva.into(), DecoderOptions::NONE);
+
+    let mut tokens = Vec::new();
+    for _ in 0..MAX_INSTRUCTIONS {
+        if !decoder.can_decode() {
+            break;
+        }
+
+        let instruction_rva = decoder.ip() as u32;
+        let instruction = decoder.decode();
+        if instruction.code() == Code::INVALID {
+            return Err(SigmakeError::InvalidInstruction(instruction_rva));
+        }
+        if instruction.code() == Code::Int3 {
+            break;
+        }
+
+        let offsets = decoder.get_constant_offsets(&instruction);
+        let is_first = tokens.is_empty();
+        let is_branch =
+            instruction.op_count() > 0 && instruction.op0_kind() == OpKind::NearBranch64;
+        let (relative_offset, relative_size) = if is_branch {
+            (offsets.immediate_offset(), offsets.immediate_size())
+        } else if instruction.is_ip_rel_memory_operand() {
+            (offsets.displacement_offset(), offsets.displacement_size())
+        } else {
+            (0, 0)
+        };
+        if is_first && target && !(is_branch && relative_size == 4) {
+            return Err(SigmakeError::NotBranch(rva));
+        }
+
+        let start = (instruction_rva - rva) as usize;
+        let bytes = &code[start..start + instruction.len()];
+        let mut offset = 0;
+        while offset < bytes.len() {
+            if relative_size > 0 && offset == relative_offset {
+                tokens.push(if relative_size == 4 {
+                    Token::Jump {
+                        capture: is_first && target,
+                    }
+                } else {
+                    Token::Wildcard
+                });
+                offset += relative_size;
+                continue;
+            }
+
+            tokens.push(if relocated.contains(&(instruction_rva + offset as u32)) {
+                Token::Wildcard
+            } else {
+                Token::Byte(bytes[offset])
+            });
+            offset += 1;
+        }
+
+        let concrete = tokens
+            .iter()
+            .filter(|token| matches!(token, Token::Byte(_)))
+            .count();
+        if concrete >= MIN_BYTES {
+            let pattern = format_pattern(&tokens);
+            if count_matches(program, &pattern) == 1 {
+                let mut captures = vec![name.to_string()];
+                if target {
+                    captures.insert(0, String::new());
+                }
+                return Ok(Signature { pattern, captures });
+            }
+        }
+
+        if matches!(instruction.mnemonic(), Mnemonic::Ret | Mnemonic::Retf) {
+            break;
+        }
+    }
+
+    Err(SigmakeError::NotUnique(rva))
+}
+
+/// Returns the RVA of every byte in [text] that's changed by a base
+/// relocation when the binary is loaded.
+fn relocated_bytes(program: PeFile<'_>, text: &Range<u32>) -> HashSet<u32> {
+    let mut relocated = HashSet::new();
+    if let Ok(relocs) = program.base_relocs() {
+        relocs.for_each(|rva, kind| {
+            let size = match kind {
+                IMAGE_REL_BASED_DIR64 => 8,
+                IMAGE_REL_BASED_HIGHLOW => 4,
+                _ => return,
+            };
+            if text.contains(&rva) {
+                relocated.extend(rva..rva + size);
+            }
+        });
+    }
+    relocated
+}
+
+/// Formats [tokens] as a pelite pattern, leaving off trailing tokens that
+/// match anything.
+fn format_pattern(tokens: &[Token]) -> String {
+    let end = tokens
+        .iter()
+        .rposition(|token| !matches!(token, Token::Wildcard | Token::Jump { capture: false }))
+        .map_or(0, |index| index + 1);
+    tokens[..end]
+        .iter()
+        .map(Token::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns how many times [pattern] matches in [program]'s code, stopping
+/// early once it's clear the pattern isn't unique.
+fn count_matches(program: PeFile<'_>, pattern: &str) -> usize {
+    let pattern = pattern::parse(pattern).expect("Generated an invalid pattern");
+    let mut save = [0u32; 2];
+    let mut matches = program.scanner().matches_code(&pattern);
+    let mut count = 0;
+    while count < 2 && matches.next(&mut save) {
+        count += 1;
+    }
+    count
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn formats_pattern() {
+        let tokens = [
+            Token::Byte(0xe8),
+            Token::Jump { capture: true },
+            Token::Byte(0x48),
+            Token::Wildcard,
+            Token::Jump { capture: false },
+            Token::Byte(0x0f),
+        ];
+        assert_eq!(format_pattern(&tokens), "e8 $ { ' } 48 ? $ {} 0f");
+    }
+
+    #[test]
+    fn pattern_leaves_off_trailing_wildcards() {
+        let tokens = [
+            Token::Byte(0x48),
+            Token::Jump { capture: true },
+            Token::Wildcard,
+            Token::Jump { capture: false },
+        ];
+        assert_eq!(format_pattern(&tokens), "48 $ { ' }");
+        assert_eq!(format_pattern(&[Token::Wildcard]), "");
+    }
+}
@@ -0,0 +1,212 @@
+//! Reports on how well each capture in a profile matches a binary.
+
+use std::fmt::Write;
+
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+/// How to print a verification report.
+#[derive(ValueEnum, Clone, Copy)]
+pub enum ReportFormat {
+    /// A table with one row per capture, followed by a summary.
+    Table,
+    /// A JSON object listing every capture.
+    Json,
+}
+
+/// Every distinct RVA found for a single capture in a binary.
+#[derive(Debug, Serialize)]
+pub struct CaptureReport {
+    /// The name of the capture in the profile.
+    pub name: String,
+
+    /// A description of the profile entry the capture comes from, such as its
+    /// pattern or its VMT class and index.
+    pub source: String,
+
+    /// The RVAs the capture matched, in the order they were found.
+    #[serde(serialize_with = "serialize_rvas")]
+    pub candidates: Vec<u32>,
+}
+
+impl CaptureReport {
+    /// Returns whether the capture matched zero, one, or several RVAs.
+    pub fn status(&self) -> CaptureStatus {
+        match self.candidates.len() {
+            0 => CaptureStatus::Missing,
+            1 => CaptureStatus::Unique,
+            _ => CaptureStatus::Ambiguous,
+        }
+    }
+}
+
+/// How many RVAs a capture matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptureStatus {
+    /// The capture didn't match anything, so mapping it emits no RVA.
+    Missing,
+    /// The capture matched exactly one RVA.
+    #[serde(rename = "ok")]
+    Unique,
+    /// The capture matched several different RVAs, so mapping it picks one
+    /// of them arbitrarily.
+    Ambiguous,
+}
+
+impl CaptureStatus {
+    /// Returns whether this status means the capture can be mapped reliably.
+    pub fn is_ok(self) -> bool {
+        self == CaptureStatus::Unique
+    }
+
+    /// Returns the name of this status as it appears in reports.
+    fn name(self) -> &'static str {
+        match self {
+            CaptureStatus::Missing => "missing",
+            CaptureStatus::Unique => "ok",
+            CaptureStatus::Ambiguous => "ambiguous",
+        }
+    }
+}
+
+/// A single capture's entry in a JSON report.
+#[derive(Serialize)]
+struct JsonCapture<'a> {
+    status: CaptureStatus,
+    #[serde(flatten)]
+    report: &'a CaptureReport,
+}
+
+/// A complete JSON report.
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    missing: usize,
+    ambiguous: usize,
+    captures: Vec<JsonCapture<'a>>,
+}
+
+/// Formats [reports] as a human-readable table or as JSON.
+pub fn format_reports(reports: &[CaptureReport], format: ReportFormat) -> String {
+    let count = |status| {
+        reports
+            .iter()
+            .filter(|report| report.status() == status)
+            .count()
+    };
+    let missing = count(CaptureStatus::Missing);
+    let ambiguous = count(CaptureStatus::Ambiguous);
+
+    match format {
+        ReportFormat::Json => {
+            let report = JsonReport {
+                missing,
+                ambiguous,
+                captures: reports
+                    .iter()
+                    .map(|report| JsonCapture {
+                        status: report.status(),
+                        report,
+                    })
+                    .collect(),
+            };
+            let mut output = serde_json::to_string_pretty(&report).unwrap();
+            output.push('\n');
+            output
+        }
+        ReportFormat::Table => {
+            let name_width = reports
+                .iter()
+                .map(|report| report.name.len())
+                .max()
+                .unwrap_or_default();
+
+            let mut output = String::new();
+            for report in reports {
+                let status = report.status();
+                writeln!(
+                    output,
+                    "{:<9}  {:<name_width$}  {}",
+                    status.name(),
+                    report.name,
+                    format_candidates(&report.candidates)
+                )
+                .unwrap();
+                if !status.is_ok() {
+                    writeln!(output, "{:<9}  {}", "", report.source).unwrap();
+                }
+            }
+
+            writeln!(
+                output,
+                "\n{} captures: {} ok, {missing} missing, {ambiguous} ambiguous",
+                reports.len(),
+                reports.len() - missing - ambiguous
+            )
+            .unwrap();
+            output
+        }
+    }
+}
+
+/// Formats each of [rvas] as hex.
+fn format_rvas(rvas: &[u32]) -> Vec<String> {
+    rvas.iter().map(|rva| format!("{rva:#x}")).collect()
+}
+
+/// Formats [rvas] for a table cell, or `-` if there aren't any.
+pub fn format_candidates(rvas: &[u32]) -> String {
+    if rvas.is_empty() {
+        "-".to_string()
+    } else {
+        format_rvas(rvas).join(", ")
+    }
+}
+
+/// Serializes [rvas] as hex strings, which are easier to compare against a
+/// disassembler than decimal numbers.
+pub fn serialize_rvas<S: Serializer>(rvas: &[u32], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(format_rvas(rvas))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn reports() -> Vec<CaptureReport> {
+        [
+            ("ok", &[0x10][..]),
+            ("missing", &[]),
+            ("ambiguous", &[0x20, 0x30]),
+        ]
+        .into_iter()
+        .map(|(name, candidates)| CaptureReport {
+            name: name.to_string(),
+            source: format!("pattern for {name}"),
+            candidates: candidates.to_vec(),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn counts_statuses_in_table() {
+        let output = format_reports(&reports(), ReportFormat::Table);
+        assert!(output.ends_with("\n3 captures: 1 ok, 1 missing, 1 ambiguous\n"));
+        assert!(output.contains("ambiguous  ambiguous  0x20, 0x30\n"));
+
+        // Only captures that aren't ok list their source.
+        assert!(!output.contains("pattern for ok"));
+        assert!(output.contains("pattern for missing"));
+    }
+
+    #[test]
+    fn counts_statuses_in_json() {
+        let output = format_reports(&reports(), ReportFormat::Json);
+        let json = serde_json::from_str::<serde_json::Value>(&output).unwrap();
+        assert_eq!(json["missing"], 1);
+        assert_eq!(json["ambiguous"], 1);
+        assert_eq!(json["captures"][0]["status"], "ok");
+        assert_eq!(json["captures"][1]["status"], "missing");
+        assert_eq!(json["captures"][2]["candidates"][1], "0x30");
+    }
+}
@@ -0,0 +1,264 @@
+//! Finds functions through the string literals they reference.
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+use iced_x86::{Code, Decoder, DecoderOptions, Instruction, Mnemonic, OpKind};
+use pelite::{
+    image::{IMAGE_SCN_MEM_EXECUTE, RUNTIME_FUNCTION, UNW_FLAG_CHAININFO, UNWIND_INFO},
+    pe64::{Pe, PeFile},
+};
+
+/// The maximum number of instructions to look through after a reference when
+/// looking for a call, in case the function's end isn't known.
+const MAX_CALL_DISTANCE: usize = 512;
+
+/// The longest an instruction can be before its `rip`-relative displacement,
+/// including prefixes, opcode, and ModRM byte.
+const MAX_DISPLACEMENT_OFFSET: usize = 7;
+
+/// Returns the RVA of each null-terminated copy of [text] in [program]'s data
+/// sections. If [wide] is true, this looks for UTF-16 strings instead.
+///
+/// Only strings that start right after another null byte count, so that a
+/// string isn't found at the end of a longer string.
+pub fn find_strings(program: PeFile<'_>, text: &str, wide: bool) -> Vec<u32> {
+    let (mut needle, char_size) = if wide {
+        let bytes = text
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+        (bytes, 2)
+    } else {
+        (text.as_bytes().to_vec(), 1)
+    };
+    needle.extend(std::iter::repeat_n(0, char_size));
+
+    let mut rvas = Vec::new();
+    for section in program.section_headers() {
+        if section.Characteristics & IMAGE_SCN_MEM_EXECUTE != 0 {
+            continue;
+        }
+        let Ok(bytes) = program.get_section_bytes(section) else {
+            continue;
+        };
+        rvas.extend(
+            string_offsets(bytes, &needle, char_size)
+                .into_iter()
+                .map(|offset| section.VirtualAddress + offset as u32),
+        );
+    }
+    rvas
+}
+
+/// Returns the offset of each copy of [needle] in [bytes] that's aligned to
+/// [char_size] and starts either at the beginning of [bytes] or right after a
+/// null character.
+fn string_offsets(bytes: &[u8], needle: &[u8], char_size: usize) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    let mut offset = 0;
+    while offset + needle.len() <= bytes.len() {
+        if bytes[offset..].starts_with(needle)
+            && (offset < char_size || bytes[offset - char_size..offset].iter().all(|b| *b == 0))
+        {
+            offsets.push(offset);
+            offset += needle.len();
+        } else {
+            offset += char_size;
+        }
+    }
+    offsets
+}
+
+/// Returns the RVA of every instruction in [text] that has a `rip`-relative
+/// operand pointing to one of [targets].
+pub fn find_references(program: PeFile<'_>, text: &Range<u32>, targets: &[u32]) -> Vec<u32> {
+    let Ok(code) = program.derva_slice::<u8>(text.start, (text.end - text.start) as usize) else {
+        return Vec::new();
+    };
+    let targets = targets.iter().copied().collect::<HashSet<_>>();
+
+    let mut references = Vec::new();
+    for offset in MAX_DISPLACEMENT_OFFSET.min(code.len())..code.len().saturating_sub(4) {
+        let displacement = i32::from_le_bytes(code[offset..offset + 4].try_into().unwrap());
+        let next = text.start + offset as u32 + 4;
+
+        // Instructions like `cmp byte ptr [rip + x], 0` have an immediate
+        // after the displacement, which the address is relative to the end of.
+        let matches_target = [0, 1, 2, 4].into_iter().any(|immediate_size| {
+            targets.contains(
+                &next
+                    .wrapping_add(immediate_size)
+                    .wrapping_add_signed(displacement),
+            )
+        });
+        if !matches_target {
+            continue;
+        }
+
+        // Only count the displacement if it's actually part of an instruction
+        // that references the target. Earlier starts are tried first so that
+        // prefixes like REX aren't left off.
+        for start in offset - MAX_DISPLACEMENT_OFFSET..offset - 1 {
+            let rva = text.start + start as u32;
+            let mut decoder =
+                Decoder::with_ip(64, &code[start..], rva.into(), DecoderOptions::NONE);
+            let instruction = decoder.decode();
+            if instruction.code() != Code::INVALID
+                && instruction.is_ip_rel_memory_operand()
+                && decoder
+                    .get_constant_offsets(&instruction)
+                    .displacement_offset()
+                    == offset - start
+                && targets.contains(&(instruction.ip_rel_memory_address() as u32))
+            {
+                references.push(rva);
+                break;
+            }
+        }
+    }
+    references
+}
+
+/// Returns the start of the function containing [rva], according to
+/// [program]'s unwind data.
+///
+/// Leaf functions that don't touch the stack have no unwind data, so this
+/// returns `None` for them.
+pub fn function_start(program: PeFile<'_>, rva: u32) -> Option<u32> {
+    let mut function = runtime_function(program, rva)?;
+
+    // Functions split into several parts have chained unwind info for each
+    // part after the first, which ends with the previous part's entry.
+    loop {
+        let unwind = program.derva::<UNWIND_INFO>(function.UnwindData).ok()?;
+        if (unwind.VersionFlags >> 3) & UNW_FLAG_CHAININFO == 0 {
+            return Some(function.BeginAddress);
+        }
+
+        let codes = (unwind.CountOfCodes as u32 + 1) & !1;
+        let chained_rva = function.UnwindData + size_of::<UNWIND_INFO>() as u32 + 2 * codes;
+        let chained = *program.derva::<RUNTIME_FUNCTION>(chained_rva).ok()?;
+        if chained.BeginAddress == function.BeginAddress {
+            return None;
+        }
+        function = chained;
+    }
+}
+
+/// Returns the unwind data entry for the function (or part of a function)
+/// containing [rva].
+pub(crate) fn runtime_function(program: PeFile<'_>, rva: u32) -> Option<RUNTIME_FUNCTION> {
+    // pelite's `lookup_function_entry` compares in the wrong order, so this
+    // does its own search.
+    let functions = program.exception().ok()?.image();
+    let index = functions.partition_point(|function| function.EndAddress <= rva);
+    functions
+        .get(index)
+        .filter(|function| function.BeginAddress <= rva)
+        .copied()
+}
+
+/// Returns the target of the [n]th `call` instruction (counting from 0) after
+/// the instruction at [rva], or `None` if there aren't that many calls before
+/// the end of the function. See [call_target] for which calls have targets.
+pub fn nth_call_target(program: PeFile<'_>, text: &Range<u32>, rva: u32, n: u32) -> Option<u32> {
+    let end = runtime_function(program, rva).map_or(text.end, |function| function.EndAddress);
+    let code = program
+        .derva_slice::<u8>(rva, end.checked_sub(rva)? as usize)
+        .ok()?;
+
+    let mut decoder = Decoder::with_ip(64, code, rva.into(), DecoderOptions::NONE);
+    let mut calls = 0;
+    // Skip the referencing instruction itself.
+    let _ = decoder.decode();
+    for _ in 0..MAX_CALL_DISTANCE {
+        if !decoder.can_decode() {
+            return None;
+        }
+
+        let instruction = decoder.decode();
+        if instruction.mnemonic() != Mnemonic::Call {
+            continue;
+        }
+        if calls < n {
+            calls += 1;
+            continue;
+        }
+
+        return call_target(&instruction);
+    }
+    None
+}
+
+/// Returns the RVA that [call] calls through, for a decoder whose IPs are
+/// RVAs.
+///
+/// For direct calls, this is the function that's called. For calls through a
+/// `rip`-relative pointer, such as imports, it's the pointer's RVA. Calls
+/// through a register have no fixed target, so they return `None`.
+fn call_target(call: &Instruction) -> Option<u32> {
+    match call.op0_kind() {
+        OpKind::NearBranch64 => Some(call.near_branch_target() as u32),
+        OpKind::Memory if call.is_ip_rel_memory_operand() => {
+            Some(call.ip_rel_memory_address() as u32)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Decodes the single instruction in [bytes] at [rva].
+    fn decode(bytes: &[u8], rva: u32) -> Instruction {
+        Decoder::with_ip(64, bytes, rva.into(), DecoderOptions::NONE).decode()
+    }
+
+    #[test]
+    fn finds_call_targets() {
+        // call 0x1100
+        assert_eq!(
+            call_target(&decode(&[0xe8, 0xfb, 0x00, 0x00, 0x00], 0x1000)),
+            Some(0x1100)
+        );
+        // call qword ptr [rip + 0x2000], which calls through the pointer at
+        // 0x3006.
+        assert_eq!(
+            call_target(&decode(&[0xff, 0x15, 0x00, 0x20, 0x00, 0x00], 0x1000)),
+            Some(0x3006)
+        );
+        // call rax
+        assert_eq!(call_target(&decode(&[0xff, 0xd0], 0x1000)), None);
+        // call qword ptr [rax + 8]
+        assert_eq!(call_target(&decode(&[0xff, 0x50, 0x08], 0x1000)), None);
+    }
+
+    #[test]
+    fn strings_start_after_a_null() {
+        // The second copy is the end of `XFoo`, and the last isn't
+        // null-terminated.
+        let bytes = b"Foo\0XFoo\0\0Foo\0Foo";
+        assert_eq!(string_offsets(bytes, b"Foo\0", 1), [0, 10]);
+    }
+
+    #[test]
+    fn wide_strings_start_after_a_null_character() {
+        let wide = |text: &str| {
+            text.encode_utf16()
+                .flat_map(u16::to_le_bytes)
+                .collect::<Vec<_>>()
+        };
+        let needle = wide("AB\0");
+
+        // The first copy follows `C`, whose second byte is zero even though
+        // it isn't a null character.
+        let mut bytes = wide("CAB\0\0AB\0");
+        assert_eq!(string_offsets(&bytes, &needle, 2), [10]);
+
+        // Copies that aren't aligned to whole characters don't count.
+        bytes.insert(0, 0);
+        assert_eq!(string_offsets(&bytes, &needle, 2), Vec::<usize>::new());
+    }
+}
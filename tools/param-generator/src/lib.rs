@@ -0,0 +1,2293 @@
+//! Generates Rust bindings for params from paramdef XML files.
+//!
+//! This is usually run from a game crate's build script, which can limit the
+//! output to the param tables the crate's users have enabled as cargo
+//! features. It can also be run as a command-line tool to inspect the output.
+
+use quick_xml::de::from_str;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// The cargo feature that enables every param table.
+pub const ALL_TABLES_FEATURE: &str = "params-all";
+
+/// Generates Rust bindings for a directory of paramdef XML files.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    /// Folder containing paramdef XML files.
+    input: PathBuf,
+
+    /// Folder containing paramdex meta XML files.
+    meta: Option<PathBuf>,
+
+    /// Folder containing paramdex row name files.
+    names: Option<PathBuf>,
+
+    /// Path to the game's `FD4ParamRepository` type.
+    repository: Option<String>,
+
+    /// The names of the param tables to generate, such as
+    /// `SP_EFFECT_PARAM_ST`, or `None` to generate all of them.
+    tables: Option<BTreeSet<String>>,
+}
+
+impl Generator {
+    /// Creates a generator for the paramdef XML files in [input].
+    pub fn new(input: impl Into<PathBuf>) -> Self {
+        Self {
+            input: input.into(),
+            meta: None,
+            names: None,
+            repository: None,
+            tables: None,
+        }
+    }
+
+    /// Sets the folder containing paramdex meta XML files. These provide the
+    /// values for the enums referenced by paramdef fields, the params that ID
+    /// fields refer to, and fields' English names and display order.
+    pub fn meta(mut self, meta: impl Into<PathBuf>) -> Self {
+        self.meta = Some(meta.into());
+        self
+    }
+
+    /// Sets the folder containing paramdex row name files, such as
+    /// `EquipParamWeapon.txt`. Each line of these has a row ID followed by
+    /// its name.
+    ///
+    /// Every param with names gets a module, such as `equip_param_weapon`,
+    /// with a `ROW_NAMES` table and a constant for each named row's ID, such
+    /// as `equip_param_weapon::DAGGER`.
+    pub fn names(mut self, names: impl Into<PathBuf>) -> Self {
+        self.names = Some(names.into());
+        self
+    }
+
+    /// Sets the path to the game's `FD4ParamRepository` type, such as
+    /// `crate::fd4::FD4ParamRepository`. If this is set, row ID types get a
+    /// `resolve` method that looks up the row they refer to.
+    pub fn repository(mut self, repository: impl Into<String>) -> Self {
+        self.repository = Some(repository.into());
+        self
+    }
+
+    /// Only generates the param tables named in [tables], such as
+    /// `SP_EFFECT_PARAM_ST`, along with any passed to earlier calls. Names
+    /// that don't match a table are ignored.
+    ///
+    /// By default, every table is generated.
+    pub fn tables<S: Into<String>>(mut self, tables: impl IntoIterator<Item = S>) -> Self {
+        self.tables
+            .get_or_insert_default()
+            .extend(tables.into_iter().map(Into::into));
+        self
+    }
+
+    /// Only generates the param tables whose cargo features are enabled for
+    /// the crate whose build script is running, along with [required]. Each
+    /// table's feature has the same name as its struct, such as
+    /// `SP_EFFECT_PARAM_ST`.
+    ///
+    /// If [ALL_TABLES_FEATURE] is enabled, every table is generated instead.
+    pub fn tables_from_features<'a>(self, required: impl IntoIterator<Item = &'a str>) -> Self {
+        if env::var_os(feature_env_var(ALL_TABLES_FEATURE)).is_some() {
+            return self;
+        }
+
+        // Cargo sets a variable for every enabled feature. Those that aren't
+        // tables, like `serde`, don't match any struct so they're harmless.
+        let enabled = env::vars_os().filter_map(|(key, _)| {
+            key.to_str()?
+                .strip_prefix("CARGO_FEATURE_")
+                .map(str::to_string)
+        });
+        self.tables(enabled.chain(required.into_iter().map(str::to_string)))
+    }
+
+    /// Returns the names of every param table in the input, which are also
+    /// the names of their cargo features.
+    pub fn table_names(&self) -> io::Result<Vec<String>> {
+        let mut names = load_definitions(&self.input)?
+            .into_iter()
+            .map(|def| def.name)
+            .collect::<Vec<_>>();
+        names.dedup();
+        Ok(names)
+    }
+
+    /// Generates the Rust source for the bindings.
+    pub fn generate(&self) -> io::Result<String> {
+        let mut output = String::new();
+
+        let mut definitions = load_definitions(&self.input)?;
+        let meta = match &self.meta {
+            Some(meta) => load_meta(meta)?,
+            None => Meta::default(),
+        };
+        let names = match &self.names {
+            Some(names) => load_names(names)?,
+            None => BTreeMap::new(),
+        };
+        let has_index = definitions.first().is_some_and(|def| def.index.is_some());
+
+        // References are resolved before tables are filtered out, so that
+        // fields have the same types no matter which other tables are enabled.
+        apply_meta(&mut definitions, &meta);
+        if self.meta.is_some() {
+            check_enums(&definitions, &meta.enums)?;
+        }
+        let mut id_types = resolve_refs(&mut definitions, &meta.fields);
+        let enums = meta.enums;
+        add_named_id_types(&definitions, &names, &mut id_types);
+        if let Some(tables) = &self.tables {
+            definitions.retain(|def| tables.contains(&def.name));
+        }
+        let enabled = definitions
+            .iter()
+            .map(|def| def.name.clone())
+            .collect::<BTreeSet<_>>();
+        let (definitions, old_definitions): (Vec<_>, Vec<_>) = definitions
+            .into_iter()
+            .partition(|def| def.removed_version.is_none());
+        let resolvable = id_types
+            .values()
+            .any(|id_type| enabled.contains(&id_type.row));
+
+        // This is a plain comment rather than a module doc, since game crates
+        // `include!` the output and that doesn't allow inner attributes.
+        output.push_str("// Param bindings generated from paramdef XMLs.\n\n");
+
+        output.push_str("// DO NOT EDIT THIS FILE DIRECTLY.\n\n");
+
+        let mut imports = vec![
+            "ParamFieldError",
+            "ParamFieldInfo",
+            "ParamFieldValue",
+            "ParamValidationError",
+        ];
+        if !definitions.is_empty() {
+            imports.push("ParamFieldType");
+        }
+        if definitions.iter().any(|def| def.first_version.is_some()) {
+            imports.push("ParamVersion");
+        }
+        if resolvable {
+            imports.push("ParamId");
+        }
+
+        // Only import the string helpers that are used by visible fields.
+        let string_types = definitions
+            .iter()
+            .chain(old_definitions.iter())
+            .flat_map(|def| def.layout.iter())
+            .filter(|unit| !unit.hidden())
+            .filter_map(|unit| unit.string_type())
+            .collect::<BTreeSet<_>>();
+        if string_types.contains("fixstr") {
+            output.push_str("use std::borrow::Cow;\n\n");
+            imports.extend(["FixstrEncodeError", "decode_fixstr", "encode_fixstr"]);
+        }
+        if string_types.contains("fixstrW") {
+            imports.extend(["decode_fixstr_w", "encode_fixstr_w"]);
+        }
+        output.push_str(&format!("use shared::{{{}}};\n\n", imports.join(", ")));
+        if let Some(repository) = &self.repository
+            && resolvable
+        {
+            output.push_str(&format!("use {repository};\n\n"));
+        }
+        output.push_str("pub use shared::ParamLayout;\n\n");
+
+        output.push_str("/// Trait to perform safe param lookups.\n");
+        output.push_str("pub trait ParamDef: ParamLayout {\n");
+
+        if has_index {
+            output
+                .push_str("    /// The index in the global parameter repository of the *first*\n");
+            output
+                .push_str("    /// parameter with this definition. Some definitions are re-used\n");
+            output.push_str(
+                "    /// across multiple parameters, which are usually contiguous in the\n",
+            );
+            output.push_str("    /// repository.\n");
+            output.push_str("    const INDEX: usize;\n\n");
+        }
+
+        output.push_str(
+            "    /// The size in bytes of each row, as laid out by the paramdef. This is\n",
+        );
+        output.push_str("    /// checked against the struct's actual size at compile time.\n");
+        output.push_str("    const ROW_SIZE: usize;\n\n");
+
+        output
+            .push_str("    /// Returns the field with the given paramdef name or Rust accessor\n");
+        output.push_str("    /// name.\n");
+        output.push_str("    fn field_info(name: &str) -> Option<&'static ParamFieldInfo> {\n");
+        output.push_str("        Self::FIELDS\n");
+        output.push_str("            .iter()\n");
+        output
+            .push_str("            .find(|field| field.name == name || field.rust_name == name)\n");
+        output.push_str("    }\n\n");
+        output.push_str(
+            "    /// Returns the value of the field with the given paramdef name or Rust\n",
+        );
+        output.push_str("    /// accessor name, or `None` if there's no such field.\n");
+        output
+            .push_str("    fn get_field_by_name(&self, name: &str) -> Option<ParamFieldValue> {\n");
+        output.push_str("        Some(Self::field_info(name)?.read(self.as_bytes()))\n");
+        output.push_str("    }\n\n");
+        output
+            .push_str("    /// Sets the value of the field with the given paramdef name or Rust\n");
+        output.push_str("    /// accessor name.\n");
+        output.push_str("    fn set_field_by_name(\n");
+        output.push_str("        &mut self,\n");
+        output.push_str("        name: &str,\n");
+        output.push_str("        value: ParamFieldValue,\n");
+        output.push_str("    ) -> Result<(), ParamFieldError> {\n");
+        output.push_str("        Self::field_info(name)\n");
+        output.push_str(
+            "            .ok_or_else(|| ParamFieldError::UnknownField(name.to_string()))?\n",
+        );
+        output.push_str("            .write(self.as_bytes_mut(), &value)\n");
+        output.push_str("    }\n\n");
+        output.push_str(
+            "    /// Returns an error for every visible field whose value is outside the\n",
+        );
+        output.push_str("    /// range allowed by the paramdef.\n");
+        output.push_str("    fn validate(&self) -> Vec<ParamValidationError> {\n");
+        output.push_str("        let bytes = self.as_bytes();\n");
+        output.push_str("        Self::FIELDS\n");
+        output.push_str("            .iter()\n");
+        output.push_str("            .filter(|field| !field.hidden)\n");
+        output.push_str("            .filter_map(|field| field.check(&field.read(bytes)).err())\n");
+        output.push_str("            .collect()\n");
+        output.push_str("    }\n");
+        output.push_str("}\n\n");
+
+        if !definitions.is_empty() || !old_definitions.is_empty() {
+            output.push_str("/// Views a generated param row as its raw bytes.\n");
+            output.push_str("fn row_bytes<T>(row: &T) -> &[u8] {\n");
+            output.push_str(
+                "    // SAFETY: This is only called on generated param structs, which are\n",
+            );
+            output.push_str(
+                "    // `repr(C)`, have no padding, and only contain integers and floats.\n",
+            );
+            output.push_str(
+                "    unsafe { std::slice::from_raw_parts((row as *const T).cast(), size_of::<T>()) }\n",
+            );
+            output.push_str("}\n\n");
+
+            output.push_str("/// Views a generated param row as its raw bytes, mutably.\n");
+            output.push_str("fn row_bytes_mut<T>(row: &mut T) -> &mut [u8] {\n");
+            output.push_str(
+                "    // SAFETY: As with [row_bytes]. Additionally, every bit pattern is valid\n",
+            );
+            output.push_str("    // for every field.\n");
+            output.push_str(
+                "    unsafe { std::slice::from_raw_parts_mut((row as *mut T).cast(), size_of::<T>()) }\n",
+            );
+            output.push_str("}\n\n");
+        }
+
+        output.push_str(&generate_enums(
+            definitions.iter().chain(old_definitions.iter()),
+            &enums,
+        ));
+
+        output.push_str(&generate_id_types(
+            &id_types,
+            &enabled,
+            self.repository.is_some(),
+        ));
+
+        for definition in &definitions {
+            output.push_str(&generate_code(definition, &enums, &id_types));
+        }
+
+        // Older layouts are grouped into modules by the version that replaced
+        // them, so each module mirrors the top level as it was before that
+        // version.
+        let mut by_version: BTreeMap<u32, Vec<&StructDef>> = BTreeMap::new();
+        for definition in &old_definitions {
+            by_version
+                .entry(definition.removed_version.unwrap())
+                .or_default()
+                .push(definition);
+        }
+        for (version, definitions) in by_version {
+            output.push_str(&format!(
+                "/// Param layouts used before paramdef version {version}, for\n"
+            ));
+            output.push_str("/// params whose layouts changed in that version.\n");
+            output.push_str(&format!("pub mod before_{version} {{\n"));
+            output.push_str("    use super::*;\n\n");
+            for definition in definitions {
+                output.push_str(&generate_code(definition, &enums, &id_types));
+            }
+            output.push_str("}\n\n");
+        }
+
+        for (param, rows) in &names {
+            if let Some(id_type) = id_types.get(param)
+                && enabled.contains(&id_type.row)
+            {
+                output.push_str(&generate_row_names(param, rows, id_type));
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Generates the bindings and writes them to [output], creating its
+    /// parent directory if necessary.
+    pub fn write(&self, output: impl AsRef<Path>) -> io::Result<()> {
+        let output = output.as_ref();
+        if let Some(parent) = output.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        File::create(output)?.write_all(self.generate()?.as_bytes())
+    }
+}
+
+/// Returns the environment variable cargo sets for build scripts when
+/// [feature] is enabled.
+fn feature_env_var(feature: &str) -> String {
+    format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"))
+}
+
+/// Loads all the parameter definitions in the [input_path] directory and
+/// returns them, sorted by name.
+///
+/// This also guarantees that either all structs have indexes defined, or none
+/// do.
+fn load_definitions(input_path: impl AsRef<Path>) -> io::Result<Vec<StructDef>> {
+    let mut definitions = fs::read_dir(input_path.as_ref())?
+        .filter_map(|entry| {
+            let entry = entry.unwrap();
+            let path = entry.path();
+            if path.extension().and_then(|s| s.to_str()) != Some("xml") {
+                return None;
+            }
+
+            let content = fs::read_to_string(&path)
+                .inspect_err(|e| eprintln!("Failed to read {}: {}", path.display(), e))
+                .ok()?;
+            let param_def = from_str::<ParamDef>(&content)
+                .inspect_err(|e| eprintln!("Failed to parse {}: {}", path.display(), e))
+                .ok()?;
+            let file_name = path.file_stem()?.to_str()?;
+            Some(build_definitions(file_name, &param_def))
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+
+    // Parameters are always stored alphabetically in-game. We sort them by name
+    // so that we can provide alphabetic indices to look them up by position.
+    definitions.sort_by(|def1, def2| def1.name.cmp(&def2.name));
+
+    if let Some(has) = definitions.iter().find(|def| def.index.is_some())
+        && let Some(has_not) = definitions.iter().find(|def| def.index.is_none())
+    {
+        panic!(
+            "{} has an index defined and {} does not. Either all params must have indices or none \
+             may.",
+            has.name, has_not.name
+        );
+    }
+
+    Ok(definitions)
+}
+
+/// The metadata loaded from paramdex meta files.
+#[derive(Default)]
+struct Meta {
+    /// Enum definitions by name.
+    enums: BTreeMap<String, EnumDef>,
+    /// The metadata for each field, by paramdef file name and then by field
+    /// name.
+    fields: BTreeMap<String, BTreeMap<String, FieldMeta>>,
+    /// The order to display each paramdef's fields in, by paramdef file
+    /// name.
+    display_orders: BTreeMap<String, Vec<String>>,
+}
+
+/// Loads all the enum definitions and field metadata from the paramdex meta
+/// files in the [meta_path] directory.
+///
+/// Paramdex repeats enum definitions in every meta file that uses them, so
+/// definitions with the same name are merged. Each meta file describes the
+/// fields of the paramdef file with the same name.
+fn load_meta(meta_path: impl AsRef<Path>) -> io::Result<Meta> {
+    let mut enums = BTreeMap::new();
+    let mut fields = BTreeMap::new();
+    let mut display_orders = BTreeMap::new();
+    for entry in fs::read_dir(meta_path.as_ref())? {
+        let path = entry?.path();
+        if path.extension().and_then(|s| s.to_str()) != Some("xml") {
+            continue;
+        }
+
+        let Some(meta) = fs::read_to_string(&path)
+            .inspect_err(|e| eprintln!("Failed to read {}: {}", path.display(), e))
+            .ok()
+            .and_then(|content| {
+                from_str::<ParamMeta>(&content)
+                    .inspect_err(|e| eprintln!("Failed to parse {}: {}", path.display(), e))
+                    .ok()
+            })
+        else {
+            continue;
+        };
+
+        for def in meta.enums.into_iter().flat_map(|e| e.enums) {
+            enums.entry(def.name.clone()).or_insert(def);
+        }
+
+        let Some(file_name) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        if let Some(field_meta) = meta.fields.filter(|fields| !fields.is_empty()) {
+            fields.insert(file_name.to_string(), field_meta);
+        }
+
+        // The order is a comma-separated list of field names, which may be
+        // split across lines.
+        if let Some(order) = meta.self_meta.and_then(|meta| meta.alternative_order) {
+            let order = order
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+            if !order.is_empty() {
+                display_orders.insert(file_name.to_string(), order);
+            }
+        }
+    }
+
+    Ok(Meta {
+        enums,
+        fields,
+        display_orders,
+    })
+}
+
+/// Attaches the enums named by the `Enum` attributes and the names given by
+/// the `AltName` attributes in [meta] to the matching fields in
+/// [definitions], along with each paramdef's display order.
+///
+/// Paramdex meta names enums for fields whose paramdefs don't, and sometimes
+/// replaces a paramdef's enum with a more complete one, so the meta wins when
+/// both are present.
+fn apply_meta(definitions: &mut [StructDef], meta: &Meta) {
+    for def in definitions.iter_mut() {
+        if let Some(order) = meta.display_orders.get(&def.file_name) {
+            // Older layouts may not have every field in the order.
+            def.display_order = order
+                .iter()
+                .filter(|name| *name == "-" || def.layout.iter().any(|unit| unit.name == **name))
+                .cloned()
+                .collect();
+        }
+
+        let Some(fields) = meta.fields.get(&def.file_name) else {
+            continue;
+        };
+
+        for unit in def.layout.iter_mut() {
+            let Some(field_meta) = fields.get(&unit.name) else {
+                continue;
+            };
+            if let Some(enum_name) = field_meta.enum_name.clone() {
+                unit.field.enum_name = Some(enum_name);
+            }
+            unit.field.alt_name = field_meta.alt_name.clone();
+        }
+    }
+}
+
+/// Returns an error naming every enum that a field in [definitions] uses
+/// but that isn't in [enums].
+///
+/// Without this, those fields would silently fall back to untyped accessors
+/// whenever the vendored meta files fall behind the paramdefs.
+fn check_enums(definitions: &[StructDef], enums: &BTreeMap<String, EnumDef>) -> io::Result<()> {
+    let mut missing = BTreeMap::new();
+    for def in definitions {
+        for unit in &def.layout {
+            if let Some(name) = unit.enum_name.as_deref()
+                && !enums.contains_key(name)
+            {
+                missing
+                    .entry(name)
+                    .or_insert_with(|| format!("{}.{}", def.file_name, unit.name));
+            }
+        }
+    }
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+    let missing = missing
+        .into_iter()
+        .map(|(name, field)| format!("{name} (used by {field})"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("No meta file defines these enums: {missing}"),
+    ))
+}
+
+/// Loads the row names from every paramdex names file in the [names_path]
+/// directory, by param name and sorted by ID.
+///
+/// Each line of a names file is a row ID followed by a space and the row's
+/// name. Rows without names and lines that don't start with an ID are
+/// skipped, and only the first name is kept for IDs listed more than once.
+fn load_names(names_path: impl AsRef<Path>) -> io::Result<BTreeMap<String, Vec<(i32, String)>>> {
+    let mut names = BTreeMap::new();
+    for entry in fs::read_dir(names_path.as_ref())? {
+        let path = entry?.path();
+        if path.extension().and_then(|s| s.to_str()) != Some("txt") {
+            continue;
+        }
+        let Some(param) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+
+        let mut rows = fs::read_to_string(&path)?
+            .lines()
+            .filter_map(|line| {
+                let (id, name) = line.trim().split_once(char::is_whitespace)?;
+                let name = name.trim();
+                Some((id.parse::<i32>().ok()?, name.to_string())).filter(|_| !name.is_empty())
+            })
+            .collect::<Vec<_>>();
+        rows.sort_by_key(|(id, _)| *id);
+        rows.dedup_by_key(|(id, _)| *id);
+        if !rows.is_empty() {
+            names.insert(param.to_string(), rows);
+        }
+    }
+
+    Ok(names)
+}
+
+/// A newtype for the IDs of rows in a particular param.
+struct IdType {
+    /// The name of the newtype, such as `SpEffectParamId`.
+    name: String,
+    /// The name of the struct for the param's rows.
+    row: String,
+}
+
+/// Marks every field in [definitions] whose paramdex `Refs` name a single
+/// param as a reference to that param, and returns the ID types for all the
+/// referenced params by param name.
+///
+/// Fields that may refer to several params, usually depending on the value of
+/// another field, are left untyped. So are fields that aren't 32-bit
+/// integers.
+fn resolve_refs(
+    definitions: &mut [StructDef],
+    field_meta: &BTreeMap<String, BTreeMap<String, FieldMeta>>,
+) -> BTreeMap<String, IdType> {
+    let rows_by_file_name = definitions
+        .iter()
+        .map(|def| (def.file_name.clone(), def.name.clone()))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut id_types = BTreeMap::new();
+    for def in definitions.iter_mut() {
+        let Some(fields) = field_meta.get(&def.file_name) else {
+            continue;
+        };
+
+        for unit in def.layout.iter_mut() {
+            let Some(target) = fields.get(&unit.name).and_then(|meta| meta.refs.as_ref()) else {
+                continue;
+            };
+            if target.contains([',', '(']) || !matches!(unit.raw_type(), "i32" | "u32") {
+                continue;
+            }
+
+            let param = target.trim();
+            let Some(row) = param_file_names(param).find_map(|name| rows_by_file_name.get(&name))
+            else {
+                eprintln!(
+                    "Skipping reference to unknown param {param} in {}",
+                    def.name
+                );
+                continue;
+            };
+
+            id_types.entry(param.to_string()).or_insert_with(|| IdType {
+                name: format!("{}Id", id_type_prefix(param)),
+                row: row.clone(),
+            });
+            unit.field.reference = Some(param.to_string());
+        }
+    }
+
+    id_types
+}
+
+/// Adds ID types for every param in [names] that doesn't already have one,
+/// so that their row constants can be typed. Names for params that no
+/// paramdef describes are skipped.
+fn add_named_id_types(
+    definitions: &[StructDef],
+    names: &BTreeMap<String, Vec<(i32, String)>>,
+    id_types: &mut BTreeMap<String, IdType>,
+) {
+    let rows_by_file_name = definitions
+        .iter()
+        .map(|def| (def.file_name.clone(), def.name.clone()))
+        .collect::<BTreeMap<_, _>>();
+
+    for param in names.keys() {
+        if id_types.contains_key(param) {
+            continue;
+        }
+
+        let Some(row) = param_file_names(param).find_map(|name| rows_by_file_name.get(&name))
+        else {
+            eprintln!("Skipping row names for unknown param {param}");
+            continue;
+        };
+        id_types.insert(
+            param.clone(),
+            IdType {
+                name: format!("{}Id", id_type_prefix(param)),
+                row: row.clone(),
+            },
+        );
+    }
+}
+
+/// Returns the prefix for the ID type of the param named [param], such as
+/// `ItemLotParamMap` for `ItemLotParam_map`.
+fn id_type_prefix(param: &str) -> String {
+    param
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Returns the paramdef file names that may describe the param named [param].
+///
+/// Paramdefs are usually named after the params that use them, but some drop
+/// or add a `Param` suffix, and params that share a paramdef add their own
+/// suffixes like `ItemLotParam_map`.
+fn param_file_names(param: &str) -> impl Iterator<Item = String> {
+    let base = param.split_once('_').map_or(param, |(base, _)| base);
+    [param, base].into_iter().flat_map(|name| {
+        [
+            Some(name.to_string()),
+            name.strip_suffix("Param").map(str::to_string),
+            Some(format!("{name}Param")),
+        ]
+        .into_iter()
+        .flatten()
+    })
+}
+
+/// Generates the ID newtypes for [id_types]. Those whose rows are in
+/// [enabled] implement `ParamId`, and if [has_repository] is true, they also
+/// get methods to look up their rows.
+///
+/// The newtypes themselves are always generated, since fields of other tables
+/// use them.
+fn generate_id_types(
+    id_types: &BTreeMap<String, IdType>,
+    enabled: &BTreeSet<String>,
+    has_repository: bool,
+) -> String {
+    let mut code = String::new();
+    for (param, id_type) in id_types {
+        let IdType { name, row } = id_type;
+        code.push_str(&format!("/// The ID of a row in `{param}`.\n"));
+        code.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]\n");
+        code.push_str(&format!("pub struct {name}(pub i32);\n\n"));
+        if !enabled.contains(row) {
+            continue;
+        }
+
+        code.push_str(&format!("impl ParamId for {name} {{\n"));
+        code.push_str(&format!("    const PARAM: &str = \"{param}\";\n\n"));
+        code.push_str(&format!("    type Row = {row};\n\n"));
+        code.push_str("    fn row_id(self) -> i32 {\n        self.0\n    }\n");
+        code.push_str("}\n\n");
+
+        if has_repository {
+            code.push_str(&format!("impl {name} {{\n"));
+            code.push_str(
+                "    /// Returns the row this ID refers to, or `None` if there's no such\n",
+            );
+            code.push_str("    /// row.\n");
+            code.push_str(&format!(
+                "    pub fn resolve(self, repository: &FD4ParamRepository) -> Option<&{row}> {{\n"
+            ));
+            code.push_str("        repository.resolve(self)\n");
+            code.push_str("    }\n");
+            code.push_str("}\n\n");
+        }
+    }
+    code
+}
+
+/// Generates a module for the rows of [param] named in [rows], with a table
+/// of their names and a constant for each of their IDs.
+fn generate_row_names(param: &str, rows: &[(i32, String)], id_type: &IdType) -> String {
+    let IdType { name: id, .. } = id_type;
+    let mut code = String::new();
+    code.push_str(&format!("/// The names and IDs of rows in `{param}`.\n"));
+    code.push_str(&format!("pub mod {} {{\n", normalize_name(param)));
+    code.push_str(&format!("    use super::{id};\n\n"));
+
+    code.push_str("    /// The ID and name of every named row, sorted by ID.\n");
+    code.push_str("    pub const ROW_NAMES: &[(i32, &str)] = &[\n");
+    for (row_id, name) in rows {
+        code.push_str(&format!("        ({row_id}, {name:?}),\n"));
+    }
+    code.push_str("    ];\n\n");
+
+    code.push_str("    /// Returns the name of the row with the given ID, if it has one.\n");
+    code.push_str(&format!(
+        "    pub fn row_name(id: {id}) -> Option<&'static str> {{\n"
+    ));
+    code.push_str("        ROW_NAMES\n");
+    code.push_str("            .binary_search_by_key(&id.0, |(id, _)| *id)\n");
+    code.push_str("            .ok()\n");
+    code.push_str("            .map(|index| ROW_NAMES[index].1)\n");
+    code.push_str("    }\n");
+
+    // Rows often share names, such as upgraded copies of an item, in which
+    // case the lowest ID gets the plain name.
+    let mut used = HashSet::new();
+    for (row_id, name) in rows {
+        let Some(mut const_name) = row_const_name(name) else {
+            continue;
+        };
+        if !used.insert(const_name.clone()) {
+            const_name = format!("{const_name}_{}", row_id.unsigned_abs());
+            if !used.insert(const_name.clone()) {
+                continue;
+            }
+        }
+
+        code.push_str(&format!("\n    /// `{name}`\n"));
+        code.push_str(&format!(
+            "    pub const {const_name}: {id} = {id}({row_id});\n"
+        ));
+    }
+    code.push_str("}\n\n");
+    code
+}
+
+/// Generates Rust enums for every paramdex enum that's referenced by at least
+/// one field in [definitions].
+fn generate_enums<'a>(
+    definitions: impl Iterator<Item = &'a StructDef>,
+    enums: &BTreeMap<String, EnumDef>,
+) -> String {
+    // The native types of all the fields that use each enum, so that we can
+    // convert from each of them.
+    let mut used: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for unit in definitions.flat_map(|def| def.layout.iter()) {
+        if let Some(name) = unit.enum_name.as_deref()
+            && enums.contains_key(name)
+            && !unit.hidden()
+        {
+            used.entry(name).or_default().insert(unit.raw_type());
+        }
+    }
+
+    let mut code = String::new();
+    for (name, raw_types) in used {
+        let def = &enums[name];
+        let repr = def.native_type();
+        let variants = def.variants();
+
+        code.push_str(&format!("/// Values for the `{name}` paramdef enum.\n"));
+        code.push_str(&format!("#[repr({repr})]\n"));
+        code.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+        code.push_str("#[allow(non_camel_case_types)]\n");
+        code.push_str(&format!("pub enum {name} {{\n"));
+        for (variant, value) in &variants {
+            code.push_str(&format!("    {variant} = {value},\n"));
+        }
+        code.push_str("}\n\n");
+
+        for raw in raw_types
+            .iter()
+            .copied()
+            .chain([repr])
+            .collect::<BTreeSet<_>>()
+        {
+            code.push_str(&format!("impl TryFrom<{raw}> for {name} {{\n"));
+            code.push_str(&format!("    type Error = {raw};\n\n"));
+            code.push_str(&format!(
+                "    fn try_from(value: {raw}) -> Result<Self, Self::Error> {{\n"
+            ));
+            code.push_str("        match value {\n");
+            for (variant, value) in &variants {
+                if fits_in_type(*value, raw) {
+                    code.push_str(&format!("            {value} => Ok(Self::{variant}),\n"));
+                }
+            }
+            code.push_str("            _ => Err(value),\n");
+            code.push_str("        }\n");
+            code.push_str("    }\n");
+            code.push_str("}\n\n");
+        }
+    }
+
+    code
+}
+
+/// Generates compile-time assertions that the Rust struct for [def] has the
+/// size and field offsets calculated from the paramdef, so a mistake in the
+/// layout calculation fails the build instead of misreading game memory.
+fn generate_layout_assertions(def: &StructDef, grouped_names: &HashMap<usize, String>) -> String {
+    let mut code = String::from("const _: () = {\n");
+    code.push_str(&format!(
+        "    assert!(size_of::<{}>() == 0x{:x});\n",
+        def.name,
+        def.size()
+    ));
+    let mut checked_groups = HashSet::new();
+    for unit in def.layout.iter() {
+        let field_name = match &unit.field_type {
+            // Bitfields share a single field for each byte they're packed in.
+            FieldType::Bitfield(_) if !checked_groups.insert(unit.offset) => continue,
+            FieldType::Bitfield(_) => grouped_names[&unit.offset].clone(),
+            _ => normalize_name(&unit.name),
+        };
+        code.push_str(&format!(
+            "    assert!(std::mem::offset_of!({}, {field_name}) == 0x{:x});\n",
+            def.name, unit.offset
+        ));
+    }
+    code.push_str("};\n\n");
+    code
+}
+
+/// Generates `Serialize` and `Deserialize` implementations for [def] that go
+/// through the field metadata, so rows are keyed by their accessor names and
+/// padding is skipped.
+fn generate_serde(def: &StructDef) -> String {
+    format!(
+        "#[cfg(feature = \"serde\")]
+impl serde::Serialize for {name} {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        shared::serialize_row(self, serializer)
+    }}
+}}
+
+#[cfg(feature = \"serde\")]
+impl<'de> serde::Deserialize<'de> for {name} {{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+        shared::deserialize_row(deserializer)
+    }}
+}}
+
+",
+        name = def.name
+    )
+}
+
+fn generate_code(
+    def: &StructDef,
+    enums: &BTreeMap<String, EnumDef>,
+    id_types: &BTreeMap<String, IdType>,
+) -> String {
+    let mut code = String::new();
+
+    // Group the bitfields
+    let mut grouped_names = HashMap::new();
+
+    code.push_str("#[derive(Debug, Clone)]\n");
+    code.push_str("#[allow(non_camel_case_types)]\n");
+    code.push_str("#[repr(C)]\n");
+
+    code.push_str(&format!("pub struct {} {{\n", def.name));
+    let mut end = 0;
+    for unit in def.layout.iter() {
+        // Some paramdefs skip over bytes, usually where a field was removed.
+        // Fill these explicitly so that the struct never contains padding
+        // bytes that Rust considers uninitialized.
+        if unit.offset > end {
+            code.push_str(&format!(
+                "    implicit_pad_{end:x}: [u8; {}],\n",
+                unit.offset - end
+            ));
+        }
+        end = end.max(unit.offset + unit.size);
+
+        match &unit.field_type {
+            FieldType::Bitfield(_) => {
+                if grouped_names.contains_key(&unit.offset) {
+                    continue;
+                }
+
+                let group_name = format!("bits_{:x}", &unit.offset);
+
+                code.push_str(&format!("    {}: u8,\n", &group_name));
+
+                grouped_names.insert(unit.offset, group_name);
+            }
+            FieldType::Standard(_) => {
+                code.push_str(&format!(
+                    "    {}: {},\n",
+                    normalize_name(&unit.name),
+                    unit.field_type.native_type()
+                ));
+            }
+            FieldType::Array(inner_type, count) => {
+                code.push_str(&format!(
+                    "    {}: [{}; {}],\n",
+                    normalize_name(&unit.name),
+                    inner_type.native_type(),
+                    count,
+                ));
+            }
+        }
+    }
+    let size = def.size();
+    if size > end {
+        code.push_str(&format!(
+            "    implicit_pad_{end:x}: [u8; {}],\n",
+            size - end
+        ));
+    }
+    code.push_str("}\n\n");
+
+    code.push_str(&generate_default(def));
+
+    code.push_str(&format!("impl ParamLayout for {} {{\n", def.name));
+    code.push_str(&format!("    const NAME: &str = \"{}\";\n\n", def.name));
+    if let Some(version) = def.first_version {
+        code.push_str(&format!(
+            "    const FIRST_VERSION: Option<ParamVersion> = Some(ParamVersion({version}));\n\n"
+        ));
+    }
+    if let Some(version) = def.removed_version {
+        code.push_str(&format!(
+            "    const REMOVED_VERSION: Option<ParamVersion> = Some(ParamVersion({version}));\n\n"
+        ));
+    }
+    if let Some(version) = def.data_version {
+        code.push_str(&format!(
+            "    const PARAMDEF_VERSION: Option<u16> = Some({version});\n\n"
+        ));
+    }
+    if !def.display_order.is_empty() {
+        code.push_str(&format!(
+            "    const DISPLAY_ORDER: &[&str] = &{:?};\n\n",
+            def.display_order
+        ));
+    }
+    code.push_str("    const FIELDS: &[ParamFieldInfo] = &[\n");
+    for unit in def.layout.iter() {
+        code.push_str(&generate_field_info(unit));
+    }
+    code.push_str("    ];\n\n");
+
+    code.push_str("    fn as_bytes(&self) -> &[u8] {\n        row_bytes(self)\n    }\n\n");
+    code.push_str(
+        "    fn as_bytes_mut(&mut self) -> &mut [u8] {\n        row_bytes_mut(self)\n    }\n",
+    );
+    code.push_str("}\n\n");
+
+    code.push_str(&format!("impl ParamDef for {} {{\n", def.name));
+    if let Some(index) = def.index {
+        code.push_str(&format!("    const INDEX: usize = {index};\n"));
+    }
+    code.push_str(&format!("    const ROW_SIZE: usize = 0x{size:x};\n}}\n\n"));
+
+    code.push_str(&generate_layout_assertions(def, &grouped_names));
+
+    code.push_str(&generate_serde(def));
+
+    code.push_str(&format!("impl {} {{\n", def.name));
+    for (index, unit) in def.layout.iter().enumerate() {
+        if unit.hidden() {
+            continue;
+        }
+
+        let normalized_name = normalize_name(&unit.name);
+
+        // Fields with a known enum get typed accessors under their normal
+        // names, and the raw accessors are suffixed with `_raw`. Flags are
+        // exposed as bools instead, since their enums only name the two
+        // states.
+        let enum_name = unit.enum_name.as_deref().filter(|name| {
+            enums.contains_key(*name)
+                && !matches!(unit.field_type, FieldType::Array(_, _))
+                && !unit.is_flag()
+        });
+        // Likewise, string fields get text accessors under their normal
+        // names.
+        let string_type = unit.string_type();
+        // And so do fields that refer to rows in other params.
+        let id_type = unit
+            .reference
+            .as_ref()
+            .filter(|_| enum_name.is_none())
+            .map(|param| &id_types[param].name);
+        let raw_name = if enum_name.is_some() || string_type.is_some() || id_type.is_some() {
+            format!("{normalized_name}_raw")
+        } else {
+            normalized_name.clone()
+        };
+
+        match &unit.field_type {
+            FieldType::Bitfield(1) => {
+                let group_name = &grouped_names[&unit.offset];
+                let used_bits = unit.bit_offset;
+
+                code.push_str("    #[allow(clippy::identity_op)]\n");
+                code.push_str(&format!(
+                    "    pub fn {raw_name}(&self) -> bool {{\n        self.{group_name} & (1 << {used_bits}) != 0\n    }}\n\n"
+                ));
+
+                code.push_str("    #[allow(clippy::identity_op)]\n");
+                code.push_str(&format!(
+                    "    pub fn set_{raw_name}(&mut self, value: bool) {{\n        self.{group_name} = (self.{group_name} & !(1 << {used_bits})) | ((value as u8) << {used_bits});\n    }}\n\n"
+                ));
+            }
+            FieldType::Bitfield(bits) => {
+                let group_name = &grouped_names[&unit.offset];
+                let used_bits = unit.bit_offset;
+
+                let mask = (1 << bits) - 1;
+
+                code.push_str("    #[allow(clippy::identity_op)]\n");
+                code.push_str(&format!(
+                    "    pub fn {raw_name}(&self) -> u8 {{\n        (self.{group_name} >> {used_bits}) & 0b{mask:08b}\n    }}\n\n"
+                ));
+
+                code.push_str("    #[allow(clippy::identity_op)]\n");
+                code.push_str(&format!(
+                    "    pub fn set_{raw_name}(&mut self, value: u8) {{\n        self.{group_name} = (self.{group_name} & !(0b{mask:08b} << {used_bits})) | ((value & 0b{mask:08b}) << {used_bits});\n    }}\n\n"
+                ));
+            }
+            FieldType::Standard(_) => {
+                code.push_str(&format!(
+                    "    pub fn {}(&self) -> {} {{\n        self.{}\n    }}\n\n",
+                    raw_name,
+                    unit.field_type.native_type(),
+                    normalized_name
+                ));
+                code.push_str(&format!(
+                    "    pub fn set_{}(&mut self, value: {}) {{\n        self.{} = value;\n    }}\n\n",
+                    raw_name, unit.field_type.native_type(), normalized_name
+                ));
+            }
+            FieldType::Array(inner, count) => {
+                code.push_str(&format!(
+                    "    pub fn {}(&self) -> &[{}; {}] {{\n        &self.{}\n    }}\n\n",
+                    raw_name,
+                    inner.native_type(),
+                    count,
+                    normalized_name
+                ));
+                code.push_str(&format!(
+                    "    pub fn set_{}(&mut self, value: [{}; {}]) {{\n        self.{} = value;\n    }}\n\n",
+                    raw_name, inner.native_type(), count, normalized_name
+                ));
+            }
+        }
+
+        match string_type {
+            Some("fixstr") => {
+                code.push_str(&format!(
+                    "    pub fn {normalized_name}(&self) -> Cow<'_, str> {{\n        decode_fixstr(&self.{normalized_name})\n    }}\n\n"
+                ));
+                code.push_str(&format!(
+                    "    pub fn set_{normalized_name}(&mut self, value: &str) -> Result<(), FixstrEncodeError> {{\n        encode_fixstr(value, &mut self.{normalized_name})\n    }}\n\n"
+                ));
+            }
+            Some(_) => {
+                code.push_str(&format!(
+                    "    pub fn {normalized_name}(&self) -> String {{\n        decode_fixstr_w(&self.{normalized_name})\n    }}\n\n"
+                ));
+                code.push_str(&format!(
+                    "    pub fn set_{normalized_name}(&mut self, value: &str) {{\n        encode_fixstr_w(value, &mut self.{normalized_name});\n    }}\n\n"
+                ));
+            }
+            None => {}
+        }
+
+        // Checked setters validate against the paramdef's range before
+        // writing anything. Flags can't hold an invalid value.
+        if (unit.minimum.is_some() || unit.maximum.is_some())
+            && !matches!(unit.field_type, FieldType::Array(_, _))
+            && !unit.is_flag()
+        {
+            code.push_str(&format!(
+                "    pub fn try_set_{raw_name}(&mut self, value: {}) -> Result<(), ParamValidationError> {{\n        Self::FIELDS[{index}].check(&value.into())?;\n        self.set_{raw_name}(value);\n        Ok(())\n    }}\n\n",
+                unit.raw_type()
+            ));
+        }
+
+        if let Some(id_type) = id_type {
+            let (from_raw, to_raw) = match unit.raw_type() {
+                "i32" => (String::new(), String::new()),
+                raw_type => (" as i32".to_string(), format!(" as {raw_type}")),
+            };
+            code.push_str(&format!(
+                "    pub fn {normalized_name}(&self) -> {id_type} {{\n        {id_type}(self.{raw_name}(){from_raw})\n    }}\n\n"
+            ));
+            code.push_str(&format!(
+                "    pub fn set_{normalized_name}(&mut self, value: {id_type}) {{\n        self.set_{raw_name}(value.0{to_raw});\n    }}\n\n"
+            ));
+        }
+
+        if let Some(enum_name) = enum_name {
+            let raw_type = unit.raw_type();
+            let repr = enums[enum_name].native_type();
+            let cast = if repr == raw_type {
+                format!("value as {raw_type}")
+            } else {
+                format!("value as {repr} as {raw_type}")
+            };
+
+            code.push_str(&format!(
+                "    pub fn {normalized_name}(&self) -> Result<{enum_name}, {raw_type}> {{\n        {enum_name}::try_from(self.{raw_name}())\n    }}\n\n"
+            ));
+            code.push_str(&format!(
+                "    pub fn set_{normalized_name}(&mut self, value: {enum_name}) {{\n        self.set_{raw_name}({cast});\n    }}\n\n"
+            ));
+        }
+    }
+    code.push_str("}\n\n");
+
+    code
+}
+
+/// Generates a `Default` implementation for [def] that uses the paramdef's
+/// default values. Padding is always zeroed.
+fn generate_default(def: &StructDef) -> String {
+    let mut code = String::new();
+    // Some params have no non-zero defaults, but we generate the same
+    // implementation for consistency.
+    code.push_str("#[allow(clippy::derivable_impls)]\n");
+    code.push_str(&format!("impl Default for {} {{\n", def.name));
+    code.push_str("    fn default() -> Self {\n");
+    code.push_str("        Self {\n");
+
+    // Bitfields sharing a byte are initialized together, so collect their
+    // combined defaults first.
+    let mut groups: BTreeMap<usize, u8> = BTreeMap::new();
+    for unit in def.layout.iter() {
+        if let FieldType::Bitfield(bits) = unit.field_type {
+            let value = default_value(unit).map_or(0, |value| value as u8);
+            let mask = ((1u16 << bits) - 1) as u8;
+            *groups.entry(unit.offset).or_default() |= (value & mask) << unit.bit_offset;
+        }
+    }
+
+    let mut end = 0;
+    for unit in def.layout.iter() {
+        if unit.offset > end {
+            code.push_str(&format!(
+                "            implicit_pad_{end:x}: [0; {}],\n",
+                unit.offset - end
+            ));
+        }
+        end = end.max(unit.offset + unit.size);
+
+        match &unit.field_type {
+            FieldType::Bitfield(_) => {
+                if let Some(value) = groups.remove(&unit.offset) {
+                    code.push_str(&format!(
+                        "            bits_{:x}: 0b{value:08b},\n",
+                        unit.offset
+                    ));
+                }
+            }
+            FieldType::Standard(_) => {
+                let literal = match (unit.field_type.native_type(), default_value(unit)) {
+                    ("f32", Some(value)) => format!("{:?}", value as f32),
+                    ("f32", None) => "0.0".to_string(),
+                    (_, Some(value)) => integer_literal(unit.field_type.native_type(), value),
+                    (_, None) => "0".to_string(),
+                };
+                code.push_str(&format!(
+                    "            {}: {literal},\n",
+                    normalize_name(&unit.name)
+                ));
+            }
+            FieldType::Array(inner, count) => {
+                let zero = if inner.native_type() == "f32" {
+                    "0.0"
+                } else {
+                    "0"
+                };
+                code.push_str(&format!(
+                    "            {}: [{zero}; {count}],\n",
+                    normalize_name(&unit.name)
+                ));
+            }
+        }
+    }
+    let size = def.size();
+    if size > end {
+        code.push_str(&format!(
+            "            implicit_pad_{end:x}: [0; {}],\n",
+            size - end
+        ));
+    }
+
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code
+}
+
+/// Returns the paramdef default for [unit], or `None` if it has none or is
+/// padding.
+fn default_value(unit: &LayoutUnit) -> Option<f64> {
+    if unit.def_type == "dummy8" {
+        return None;
+    }
+
+    let default = unit.default.as_deref()?;
+    default
+        .trim()
+        .parse::<f64>()
+        .inspect_err(|_| eprintln!("Skipping non-numeric default {default}"))
+        .ok()
+}
+
+/// Converts [value] to a literal for the Rust integer type [ty].
+///
+/// Paramdex writes some defaults as rounded floats, such as `4.2949673E+09`
+/// for `u32::MAX`, so values are rounded and saturated to the type's range.
+/// Negative defaults for unsigned types, usually `-1`, wrap around as they do
+/// in the game.
+fn integer_literal(ty: &str, value: f64) -> String {
+    let value = value.round();
+    match ty {
+        "u8" if value < 0.0 => (value as i64 as u8).to_string(),
+        "u16" if value < 0.0 => (value as i64 as u16).to_string(),
+        "u32" if value < 0.0 => (value as i64 as u32).to_string(),
+        "u8" => (value as u8).to_string(),
+        "i8" => (value as i8).to_string(),
+        "u16" => (value as u16).to_string(),
+        "i16" => (value as i16).to_string(),
+        "u32" => (value as u32).to_string(),
+        "i32" => (value as i32).to_string(),
+        _ => panic!("Unknown integer type: {ty}"),
+    }
+}
+
+/// Generates the [ParamFieldInfo] literal describing [unit].
+fn generate_field_info(unit: &LayoutUnit) -> String {
+    fn option<T>(value: Option<T>, format: impl Fn(T) -> String) -> String {
+        value.map_or_else(
+            || "None".to_string(),
+            |value| format!("Some({})", format(value)),
+        )
+    }
+
+    let (bit_width, array_len) = match &unit.field_type {
+        FieldType::Bitfield(bits) => (Some(*bits), None),
+        FieldType::Standard(_) => (None, None),
+        FieldType::Array(_, count) => (None, Some(*count)),
+    };
+
+    let mut code = String::new();
+    code.push_str("        ParamFieldInfo {\n");
+    code.push_str(&format!("            name: {:?},\n", unit.name));
+    code.push_str(&format!(
+        "            rust_name: {:?},\n",
+        normalize_name(&unit.name)
+    ));
+    code.push_str(&format!(
+        "            display_name: {},\n",
+        option(unit.display_name.as_ref(), |name| format!("{name:?}"))
+    ));
+    code.push_str(&format!(
+        "            alt_name: {},\n",
+        option(unit.alt_name.as_ref(), |name| format!("{name:?}"))
+    ));
+    code.push_str(&format!(
+        "            field_type: ParamFieldType::{},\n",
+        field_type_variant(&unit.def_type)
+    ));
+    code.push_str(&format!("            offset: {:#x},\n", unit.offset));
+    code.push_str(&format!("            bit_offset: {},\n", unit.bit_offset));
+    code.push_str(&format!(
+        "            bit_width: {},\n",
+        option(bit_width, |bits| bits.to_string())
+    ));
+    code.push_str(&format!(
+        "            array_len: {},\n",
+        option(array_len, |count| count.to_string())
+    ));
+    code.push_str(&format!(
+        "            minimum: {},\n",
+        option(unit.minimum, |value| format!("{value:?}"))
+    ));
+    code.push_str(&format!(
+        "            maximum: {},\n",
+        option(unit.maximum, |value| format!("{value:?}"))
+    ));
+    code.push_str(&format!("            hidden: {},\n", unit.hidden()));
+    code.push_str("        },\n");
+    code
+}
+
+/// Returns the `ParamFieldType` variant for the paramdef type [def_type].
+fn field_type_variant(def_type: &str) -> &'static str {
+    match def_type {
+        "u8" => "U8",
+        "s8" => "S8",
+        "u16" => "U16",
+        "s16" => "S16",
+        "u32" => "U32",
+        "s32" => "S32",
+        "f32" => "F32",
+        "dummy8" => "Dummy8",
+        "fixstr" => "Fixstr",
+        "fixstrW" => "FixstrW",
+        _ => panic!("Unknown type: {def_type}"),
+    }
+}
+
+/// Builds a struct for every distinct layout of [parsed] across paramdef
+/// versions. The latest layout is returned first.
+fn build_definitions(file_name: &str, parsed: &ParamDef) -> Vec<StructDef> {
+    let fields = parsed
+        .fields
+        .fields
+        .iter()
+        .map(|f| {
+            (
+                parse_version(f.first_version.as_deref()),
+                parse_version(f.removed_version.as_deref()),
+                parse_field(f).unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // Every version where a field was added or removed starts a new layout.
+    let versions = fields
+        .iter()
+        .flat_map(|(first, removed, _)| [*first, *removed])
+        .flatten()
+        .collect::<BTreeSet<_>>();
+
+    let fields_at = |version: u32| {
+        fields
+            .iter()
+            .filter(|(first, removed, _)| {
+                first.is_none_or(|first| first <= version) && removed.is_none_or(|r| version < r)
+            })
+            .map(|(_, _, field)| field.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let mut definitions = Vec::new();
+    let mut first_version = None;
+    for removed_version in versions.iter().copied().map(Some).chain([None]) {
+        // The fields of the layout that applies from [first_version] up to
+        // [removed_version].
+        let layout_fields = fields_at(first_version.unwrap_or(0));
+
+        // Skip versions that don't change the layout, such as a field being
+        // replaced by an identical one.
+        if let Some(version) = removed_version
+            && fields_at(version)
+                .iter()
+                .map(|field| (&field.name, &field.field_type))
+                .eq(layout_fields
+                    .iter()
+                    .map(|field| (&field.name, &field.field_type)))
+        {
+            continue;
+        }
+
+        definitions.push(StructDef {
+            name: parsed.param_type.clone(),
+            file_name: file_name.to_string(),
+            index: parsed.index,
+            layout: layout_struct(&layout_fields),
+            first_version,
+            removed_version,
+            // The paramdef only records the data version of its latest
+            // layout.
+            data_version: parsed.data_version.filter(|_| removed_version.is_none()),
+            display_order: Vec::new(),
+        });
+        first_version = removed_version;
+    }
+
+    definitions.reverse();
+    definitions
+}
+
+/// Parses a paramdef version attribute.
+fn parse_version(version: Option<&str>) -> Option<u32> {
+    version.map(|version| {
+        version
+            .trim()
+            .parse()
+            .unwrap_or_else(|_| panic!("Invalid paramdef version: {version}"))
+    })
+}
+
+fn layout_struct(fields: &[LayoutField]) -> Vec<LayoutUnit> {
+    let mut offset = 0;
+    let mut layout = Vec::new();
+    let mut bit_cursor: Option<(usize, u8)> = None;
+
+    for field in fields {
+        let (alignment, size) = field.field_type.alignment_and_size();
+
+        match &field.field_type {
+            FieldType::Bitfield(bits) => {
+                // Either get the current bit cursor or start a new one
+                let (byte_offset, used_bits) = bit_cursor.unwrap_or((offset, 0));
+
+                // Wrap around the 8 bit boundaries.
+                if used_bits + bits > 8 {
+                    offset += 1;
+                    bit_cursor = Some((offset, *bits));
+                } else {
+                    bit_cursor = Some((byte_offset, used_bits + bits));
+                }
+
+                layout.push(LayoutUnit {
+                    field: field.clone(),
+                    offset,
+                    bit_offset: bit_cursor.map(|(_, used)| used - bits).unwrap_or(0),
+                    size,
+                });
+
+                // Yeet bit cursor and advance offset if we get to the end of a byte.
+                if let Some((_, used)) = bit_cursor
+                    && used == 8
+                {
+                    offset += 1;
+                    bit_cursor = None;
+                }
+            }
+            FieldType::Standard(_) | FieldType::Array(_, _) => {
+                // Clean bit cursor if we're in the middle of a byte and need to enforce alignment.
+                if bit_cursor.is_some() {
+                    offset += 1;
+                    bit_cursor = None;
+                }
+
+                // Align to current types alignment.
+                offset = align_offset(offset, alignment);
+                layout.push(LayoutUnit {
+                    field: field.clone(),
+                    offset,
+                    bit_offset: 0,
+                    size,
+                });
+                offset += size;
+            }
+        }
+    }
+
+    layout
+}
+
+fn parse_field(field: &FieldDef) -> Option<LayoutField> {
+    let (main, default) = if let Some(pos) = field.def.find('=') {
+        (
+            field.def[..pos].trim(),
+            Some(field.def[pos + 1..].trim().to_string()),
+        )
+    } else {
+        (field.def.as_str(), None)
+    };
+
+    let mut parts = main.split_whitespace();
+    let orig_type = parts.next()?.to_string();
+    let remainder = parts.next()?.to_string();
+    let mut name = remainder.clone();
+    let mut bit_width = None;
+    let mut array_size = None;
+
+    if let Some(colon) = remainder.find(':') {
+        name = remainder[..colon].to_string();
+        let after = &remainder[colon + 1..];
+        let (bw_str, rest) = if let Some(bracket) = after.find('[') {
+            (&after[..bracket], &after[bracket..])
+        } else {
+            (after, "")
+        };
+        bit_width = bw_str.parse::<u32>().ok();
+        if rest.starts_with('[') && rest.ends_with(']') {
+            array_size = rest[1..rest.len() - 1].parse().ok();
+        }
+    } else if let Some(bracket) = remainder.find('[') {
+        name = remainder[..bracket].to_string();
+        let closing = remainder.find(']').unwrap_or(remainder.len());
+        array_size = remainder[bracket + 1..closing].parse().ok();
+    }
+
+    let field_type = match (bit_width, array_size) {
+        (None, None) => FieldType::Standard(orig_type.clone()),
+        (None, Some(array_size)) => {
+            FieldType::Array(Box::new(FieldType::Standard(orig_type.clone())), array_size)
+        }
+        (Some(bit_width), None) => FieldType::Bitfield(bit_width as u8),
+        (Some(_), Some(_)) => unimplemented!(),
+    };
+
+    let minimum = parse_limit(&orig_type, field.minimum.as_deref());
+    let maximum = parse_limit(&orig_type, field.maximum.as_deref());
+    Some(LayoutField {
+        name,
+        def_type: orig_type,
+        field_type,
+        enum_name: field.enum_name.clone(),
+        default,
+        display_name: field.display_name.clone(),
+        alt_name: None,
+        minimum,
+        maximum,
+        reference: None,
+    })
+}
+
+/// Parses a paramdef `<Minimum>` or `<Maximum>` value for a field of type
+/// [def_type].
+///
+/// Paramdex has a number of limits that can't be represented by their fields'
+/// types, such as `u32` maximums that overflowed to negative numbers. These
+/// don't restrict anything in practice, so they're dropped.
+fn parse_limit(def_type: &str, value: Option<&str>) -> Option<f64> {
+    let value = value?;
+    let limit = value
+        .trim()
+        .parse::<f64>()
+        .inspect_err(|_| eprintln!("Skipping non-numeric limit {value}"))
+        .ok()?;
+
+    let (min, max) = match def_type {
+        "f32" => return Some(limit),
+        "u8" => (u8::MIN.into(), u8::MAX.into()),
+        "s8" => (i8::MIN.into(), i8::MAX.into()),
+        "u16" => (u16::MIN.into(), u16::MAX.into()),
+        "s16" => (i16::MIN.into(), i16::MAX.into()),
+        "u32" => (u32::MIN.into(), u32::MAX.into()),
+        "s32" => (i32::MIN.into(), i32::MAX.into()),
+        // Limits on padding and strings are meaningless.
+        _ => return None,
+    };
+    Some(limit).filter(|limit| (min..=max).contains(limit))
+}
+
+fn normalize_name(mut name: &str) -> String {
+    // type is a reserved keyword, so we cannot name properties that.
+    if name == "type" {
+        name = "typ";
+    }
+
+    let mut result = String::with_capacity(name.len());
+    let mut prev_char: Option<char> = None;
+
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_ascii_alphanumeric() {
+            if ch.is_uppercase() {
+                if let Some(prev) = prev_char {
+                    if prev.is_lowercase() || (prev.is_numeric() && i > 0) {
+                        result.push('_');
+                    } else if prev.is_uppercase() {
+                        // Look ahead to avoid splitting acronyms prematurely
+                        if let Some(next) = name.chars().nth(i + 1)
+                            && next.is_lowercase()
+                        {
+                            result.push('_');
+                        }
+                    }
+                }
+                result.push(ch.to_ascii_lowercase());
+            } else {
+                result.push(ch);
+            }
+        } else {
+            // Replace symbols (non-alphanumerics) with a single underscore
+            if !result.ends_with('_') {
+                result.push('_');
+            }
+        }
+        prev_char = Some(ch);
+    }
+
+    // Remove leading/trailing/multiple underscores
+    result
+        .split('_')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Converts a paramdex enum option name into a valid Rust variant name.
+fn variant_name(name: &str, value: i64) -> String {
+    let mut result = String::with_capacity(name.len());
+    for word in name.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            result.push(first.to_ascii_uppercase());
+            result.extend(chars);
+        }
+    }
+
+    // Fall back to a name based on the value for options whose names are
+    // entirely non-ASCII, such as untranslated Japanese names.
+    if result.is_empty() || !name.is_ascii() {
+        if value < 0 {
+            format!("ValueNeg{}", value.unsigned_abs())
+        } else {
+            format!("Value{value}")
+        }
+    } else if result.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{result}")
+    } else {
+        result
+    }
+}
+
+/// Converts a paramdex row name into a Rust constant name, such as
+/// `MIQUELLAS_LILY` for `Miquella's Lily`. Returns `None` for names that
+/// aren't entirely ASCII, such as untranslated Japanese names.
+fn row_const_name(name: &str) -> Option<String> {
+    let name = name.replace(['\'', '’'], "");
+    if !name.is_ascii() {
+        return None;
+    }
+
+    let result = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_uppercase())
+        .collect::<Vec<_>>()
+        .join("_");
+    if result.is_empty() {
+        None
+    } else if result.starts_with(|c: char| c.is_ascii_digit()) {
+        Some(format!("ROW_{result}"))
+    } else {
+        Some(result)
+    }
+}
+
+/// Returns whether [value] can be represented by the Rust primitive [ty].
+fn fits_in_type(value: i64, ty: &str) -> bool {
+    match ty {
+        "u8" => u8::try_from(value).is_ok(),
+        "i8" => i8::try_from(value).is_ok(),
+        "u16" => u16::try_from(value).is_ok(),
+        "i16" => i16::try_from(value).is_ok(),
+        "u32" => u32::try_from(value).is_ok(),
+        "i32" => i32::try_from(value).is_ok(),
+        _ => false,
+    }
+}
+
+fn align_offset(offset: usize, align: usize) -> usize {
+    (offset + align - 1) & !(align - 1)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename = "PARAMDEF")]
+struct ParamDef {
+    #[serde(rename = "ParamType")]
+    param_type: String,
+    #[serde(rename = "Index")]
+    index: Option<usize>,
+    #[serde(rename = "DataVersion")]
+    data_version: Option<u16>,
+    #[serde(rename = "Fields")]
+    fields: Fields,
+}
+
+#[derive(Debug, Deserialize)]
+struct Fields {
+    #[serde(rename = "Field")]
+    fields: Vec<FieldDef>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FieldDef {
+    #[serde(rename = "@Def")]
+    def: String,
+    #[serde(rename = "@FirstVersion")]
+    first_version: Option<String>,
+    #[serde(rename = "@RemovedVersion")]
+    removed_version: Option<String>,
+    #[serde(rename = "Enum")]
+    enum_name: Option<String>,
+    #[serde(rename = "DisplayName")]
+    display_name: Option<String>,
+    #[serde(rename = "Minimum")]
+    minimum: Option<String>,
+    #[serde(rename = "Maximum")]
+    maximum: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename = "PARAMMETA")]
+struct ParamMeta {
+    #[serde(rename = "Self")]
+    self_meta: Option<SelfMeta>,
+    #[serde(rename = "Field")]
+    fields: Option<BTreeMap<String, FieldMeta>>,
+    #[serde(rename = "Enums")]
+    enums: Option<Enums>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SelfMeta {
+    #[serde(rename = "@AlternativeOrder")]
+    alternative_order: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FieldMeta {
+    #[serde(rename = "@Refs")]
+    refs: Option<String>,
+    #[serde(rename = "@Enum")]
+    enum_name: Option<String>,
+    #[serde(rename = "@AltName")]
+    alt_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Enums {
+    #[serde(rename = "Enum", default)]
+    enums: Vec<EnumDef>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EnumDef {
+    #[serde(rename = "@Name")]
+    name: String,
+    #[serde(rename = "@type")]
+    enum_type: String,
+    #[serde(rename = "Option", default)]
+    options: Vec<EnumOption>,
+}
+
+impl EnumDef {
+    /// The Rust primitive used to represent this enum.
+    fn native_type(&self) -> &str {
+        FieldType::Standard(self.enum_type.clone()).native_type()
+    }
+
+    /// Returns the Rust variant names and values for this enum's options.
+    /// Options whose values can't be represented by the enum's type are
+    /// dropped.
+    fn variants(&self) -> Vec<(String, i64)> {
+        let mut variants: Vec<(String, i64)> = Vec::new();
+        for option in &self.options {
+            let Ok(value) = option.value.trim().parse::<i64>() else {
+                eprintln!(
+                    "Skipping non-integer value {} in {}",
+                    option.value, self.name
+                );
+                continue;
+            };
+            if !fits_in_type(value, self.native_type()) || variants.iter().any(|(_, v)| *v == value)
+            {
+                continue;
+            }
+
+            let mut name = variant_name(&option.name, value);
+            if variants.iter().any(|(n, _)| *n == name) {
+                name = format!("{name}{}", value.unsigned_abs());
+            }
+            variants.push((name, value));
+        }
+        variants
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct EnumOption {
+    #[serde(rename = "@Value")]
+    value: String,
+    #[serde(rename = "@Name")]
+    name: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum FieldType {
+    Bitfield(u8),
+    Standard(String),
+    Array(Box<FieldType>, usize),
+}
+
+impl FieldType {
+    fn alignment_and_size(&self) -> (usize, usize) {
+        match self {
+            FieldType::Bitfield(_) => (1, 1),
+            FieldType::Standard(ty) => match ty.as_str() {
+                "u8" | "s8" | "dummy8" | "fixstr" => (1, 1),
+                "u16" | "s16" | "fixstrW" => (2, 2),
+                "u32" | "s32" | "f32" => (4, 4),
+                _ => panic!("Unknown type: {ty}"),
+            },
+            FieldType::Array(inner_type, repetitions) => (
+                inner_type.alignment_and_size().0,
+                inner_type.alignment_and_size().1 * repetitions,
+            ),
+        }
+    }
+
+    fn native_type(&self) -> &'static str {
+        match self {
+            FieldType::Standard(ty) => match ty.as_str() {
+                "u8" | "dummy8" | "fixstr" => "u8",
+                "s8" => "i8",
+                "u16" | "fixstrW" => "u16",
+                "s16" => "i16",
+                "u32" => "u32",
+                "f32" => "f32",
+                "s32" => "i32",
+                _ => panic!("Unknown type: {ty}"),
+            },
+            _ => unimplemented!(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct StructDef {
+    name: String,
+    /// The name of the paramdef file this was loaded from, without the
+    /// extension.
+    file_name: String,
+    index: Option<usize>,
+    layout: Vec<LayoutUnit>,
+    /// The first paramdef version that uses this layout, or `None` if it's
+    /// the earliest layout.
+    first_version: Option<u32>,
+    /// The first paramdef version that no longer uses this layout, or `None`
+    /// if it's the latest layout.
+    removed_version: Option<u32>,
+    /// The paramdef version that `.param` files with this layout store in
+    /// their headers, if it's known.
+    data_version: Option<u16>,
+    /// The field names from the paramdex meta's `AlternativeOrder`, with `-`
+    /// separating groups.
+    display_order: Vec<String>,
+}
+
+impl StructDef {
+    /// The size in bytes of each row, including trailing alignment.
+    fn size(&self) -> usize {
+        let alignment = self
+            .layout
+            .iter()
+            .map(|unit| unit.field_type.alignment_and_size().0)
+            .max()
+            .unwrap_or(1);
+        let end = self
+            .layout
+            .iter()
+            .map(|unit| unit.offset + unit.size)
+            .max()
+            .unwrap_or(0);
+        align_offset(end, alignment)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct LayoutField {
+    name: String,
+    /// The type as written in the paramdef, such as `u16` for `u16 foo:1`.
+    def_type: String,
+    field_type: FieldType,
+    enum_name: Option<String>,
+    /// The default value as written in the paramdef, such as `-1` for
+    /// `s32 foo = -1`.
+    default: Option<String>,
+    display_name: Option<String>,
+    /// The English name from the paramdex meta's `AltName`, if any.
+    alt_name: Option<String>,
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    /// The name of the param whose row IDs this field holds, if it's known.
+    reference: Option<String>,
+}
+
+#[derive(Debug)]
+struct LayoutUnit {
+    field: LayoutField,
+    offset: usize,
+    /// The offset of the first bit of this field within the byte at
+    /// [offset]. Always 0 for non-bitfields.
+    bit_offset: u8,
+    size: usize,
+}
+
+impl std::ops::Deref for LayoutUnit {
+    type Target = LayoutField;
+
+    fn deref(&self) -> &LayoutField {
+        &self.field
+    }
+}
+
+impl LayoutUnit {
+    /// The Rust type returned by this field's raw getter.
+    fn raw_type(&self) -> &'static str {
+        match &self.field_type {
+            FieldType::Bitfield(_) => "u8",
+            FieldType::Standard(_) => self.field_type.native_type(),
+            FieldType::Array(inner, _) => inner.native_type(),
+        }
+    }
+
+    /// Returns whether this field is a single-bit flag.
+    fn is_flag(&self) -> bool {
+        self.field_type == FieldType::Bitfield(1)
+    }
+
+    /// Returns `fixstr` or `fixstrW` if this field is a string, which is
+    /// always an array of characters.
+    fn string_type(&self) -> Option<&'static str> {
+        match &self.field_type {
+            FieldType::Array(inner, _) => match &**inner {
+                FieldType::Standard(ty) if ty == "fixstr" => Some("fixstr"),
+                FieldType::Standard(ty) if ty == "fixstrW" => Some("fixstrW"),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn hidden(&self) -> bool {
+        if FieldType::Standard("dummy8".to_string()) == self.field_type {
+            return true;
+        }
+
+        if let FieldType::Array(inner, _) = &self.field_type
+            && FieldType::Standard("dummy8".to_string()) == **inner
+        {
+            return true;
+        }
+
+        let lower = self.name.to_lowercase();
+        lower.contains("reserve") || lower.starts_with("pad") || lower.starts_with("unk")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A scratch paramdex folder with `Defs`, `Meta`, and `Names`
+    /// subfolders, which is deleted when it's dropped.
+    struct Paramdex(PathBuf);
+
+    impl Paramdex {
+        fn new(name: &str) -> Self {
+            let path =
+                env::temp_dir().join(format!("param-generator-{}-{name}", std::process::id()));
+            let _ = fs::remove_dir_all(&path);
+            for folder in ["Defs", "Meta", "Names"] {
+                fs::create_dir_all(path.join(folder)).unwrap();
+            }
+            Self(path)
+        }
+
+        /// Writes [contents] to [file], relative to the paramdex root.
+        fn write(&self, file: &str, contents: &str) -> &Self {
+            fs::write(self.0.join(file), contents).unwrap();
+            self
+        }
+
+        /// Copies the Elden Ring paramdef named [file] into `Defs`.
+        fn copy_elden_ring_def(&self, file: &str) -> &Self {
+            let source = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../../crates/eldenring/paramdex/Defs")
+                .join(file);
+            fs::copy(source, self.0.join("Defs").join(file)).unwrap();
+            self
+        }
+
+        /// Writes `Meta/Enums.xml`, defining every enum that the paramdefs
+        /// use and the other meta files don't, so that copied paramdefs pass
+        /// the enum check. Call this after writing the other meta files.
+        fn define_other_enums(&self) -> &Self {
+            let definitions = load_definitions(self.0.join("Defs")).unwrap();
+            let defined = load_meta(self.0.join("Meta")).unwrap().enums;
+            let enums = definitions
+                .iter()
+                .flat_map(|def| def.layout.iter())
+                .filter_map(|unit| unit.enum_name.as_deref())
+                .filter(|name| !defined.contains_key(*name))
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .map(|name| {
+                    format!(
+                        r#"<Enum Name="{name}" type="u8"><Option Value="0" Name="None" /></Enum>"#
+                    )
+                })
+                .collect::<String>();
+            self.write(
+                "Meta/Enums.xml",
+                &format!(r#"<PARAMMETA XmlVersion="0"><Enums>{enums}</Enums></PARAMMETA>"#),
+            )
+        }
+
+        fn generate(&self) -> String {
+            self.generator().generate().unwrap()
+        }
+
+        fn generator(&self) -> Generator {
+            Generator::new(self.0.join("Defs"))
+                .meta(self.0.join("Meta"))
+                .names(self.0.join("Names"))
+        }
+    }
+
+    impl Drop for Paramdex {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// A paramdef for `TEST_PARAM_ST` with the given `<Field>` elements.
+    fn test_def(fields: &str) -> String {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+             <PARAMDEF XmlVersion=\"2\">\n\
+             <ParamType>TEST_PARAM_ST</ParamType>\n\
+             <Fields>{fields}</Fields>\n\
+             </PARAMDEF>"
+        )
+    }
+
+    #[test]
+    fn paramdef_version_only_on_latest_layout() {
+        let paramdex = Paramdex::new("paramdef-version");
+        paramdex.write(
+            "Defs/TestParam.xml",
+            &test_def(r#"<Field Def="s32 old" /><Field Def="s32 new" FirstVersion="20" />"#)
+                .replace("<Fields>", "<DataVersion>4</DataVersion><Fields>"),
+        );
+
+        let output = paramdex.generate();
+        let (latest, old) = output.split_once("pub mod before_20 {").unwrap();
+        assert!(latest.contains("const PARAMDEF_VERSION: Option<u16> = Some(4);"));
+        assert!(!old.contains("PARAMDEF_VERSION"));
+    }
+
+    #[test]
+    fn paramdef_enum_gets_typed_accessor() {
+        let paramdex = Paramdex::new("paramdef-enum");
+        paramdex
+            .copy_elden_ring_def("EquipParamWeapon.xml")
+            .write(
+                "Meta/EquipParamWeapon.xml",
+                r#"<PARAMMETA XmlVersion="0">
+                  <Enums>
+                    <Enum Name="WEP_TYPE" type="u16">
+                      <Option Value="1" Name="Dagger" />
+                      <Option Value="3" Name="Straight Sword" />
+                    </Enum>
+                  </Enums>
+                </PARAMMETA>"#,
+            )
+            .define_other_enums();
+
+        let output = paramdex.generate();
+        assert!(output.contains("pub enum WEP_TYPE {\n    Dagger = 1,\n"));
+        assert!(output.contains("pub fn wep_type(&self) -> Result<WEP_TYPE, u16> {"));
+        assert!(output.contains("pub fn set_wep_type(&mut self, value: WEP_TYPE) {"));
+        assert!(output.contains("pub fn wep_type_raw(&self) -> u16 {"));
+    }
+
+    #[test]
+    fn meta_enum_attribute_gets_typed_accessor() {
+        let paramdex = Paramdex::new("meta-enum");
+        paramdex
+            .write(
+                "Defs/TestParam.xml",
+                &test_def(r#"<Field Def="s32 category" /><Field Def="s32 other" />"#),
+            )
+            .write(
+                "Meta/TestParam.xml",
+                r#"<PARAMMETA XmlVersion="0">
+                  <Field>
+                    <category Enum="TEST_CATEGORY" />
+                  </Field>
+                  <Enums>
+                    <Enum Name="TEST_CATEGORY" type="s32">
+                      <Option Value="0" Name="None" />
+                    </Enum>
+                  </Enums>
+                </PARAMMETA>"#,
+            );
+
+        let output = paramdex.generate();
+        assert!(output.contains("pub fn category(&self) -> Result<TEST_CATEGORY, i32> {"));
+        assert!(output.contains("pub fn other(&self) -> i32 {"));
+    }
+
+    #[test]
+    fn single_ref_gets_id_type() {
+        let paramdex = Paramdex::new("single-ref");
+        paramdex
+            .copy_elden_ring_def("NpcParam.xml")
+            .copy_elden_ring_def("BehaviorParam.xml")
+            .copy_elden_ring_def("BulletParam.xml")
+            .write(
+                "Meta/NpcParam.xml",
+                r#"<PARAMMETA XmlVersion="0">
+                  <Field>
+                    <behaviorVariationId Refs="BehaviorParam" />
+                    <itemLotId_enemy Refs="ItemLotParam_enemy,BulletParam" />
+                  </Field>
+                </PARAMMETA>"#,
+            )
+            .define_other_enums();
+
+        let output = paramdex
+            .generator()
+            .repository("crate::fd4::FD4ParamRepository")
+            .generate()
+            .unwrap();
+        assert!(output.contains("pub struct BehaviorParamId(pub i32);"));
+        assert!(output.contains("impl ParamId for BehaviorParamId {"));
+        assert!(output.contains(
+            "pub fn resolve(self, repository: &FD4ParamRepository) -> Option<&BEHAVIOR_PARAM_ST> {"
+        ));
+        assert!(output.contains("pub fn behavior_variation_id(&self) -> BehaviorParamId {"));
+        assert!(output.contains("pub fn behavior_variation_id_raw(&self) -> i32 {"));
+
+        // References to several params stay untyped.
+        assert!(!output.contains("BulletParamId"));
+        assert!(output.contains("pub fn item_lot_id_enemy(&self) -> i32 {"));
+    }
+
+    #[test]
+    fn ref_without_repository_has_no_resolve() {
+        let paramdex = Paramdex::new("ref-without-repository");
+        paramdex
+            .copy_elden_ring_def("NpcParam.xml")
+            .copy_elden_ring_def("BehaviorParam.xml")
+            .write(
+                "Meta/NpcParam.xml",
+                r#"<PARAMMETA XmlVersion="0">
+                  <Field>
+                    <behaviorVariationId Refs="BehaviorParam" />
+                  </Field>
+                </PARAMMETA>"#,
+            )
+            .define_other_enums();
+
+        let output = paramdex.generate();
+        assert!(output.contains("impl ParamId for BehaviorParamId {"));
+        assert!(!output.contains("pub fn resolve("));
+    }
+
+    #[test]
+    fn undefined_enum_is_an_error() {
+        let paramdex = Paramdex::new("undefined-enum");
+        paramdex.write(
+            "Defs/TestParam.xml",
+            &test_def(r#"<Field Def="u8 category"><Enum>TEST_CATEGORY</Enum></Field>"#),
+        );
+
+        let error = paramdex.generator().generate().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(
+            error
+                .to_string()
+                .contains("TEST_CATEGORY (used by TestParam.category)")
+        );
+
+        // Without meta files, enums are left untyped rather than checked.
+        let output = Generator::new(paramdex.0.join("Defs")).generate().unwrap();
+        assert!(output.contains("pub fn category(&self) -> u8 {"));
+    }
+
+    #[test]
+    fn meta_alt_names_and_display_order() {
+        let paramdex = Paramdex::new("alt-names");
+        paramdex
+            .write(
+                "Defs/TestParam.xml",
+                &test_def(
+                    r#"<Field Def="s32 first" />
+                       <Field Def="s32 second" />
+                       <Field Def="s32 third" FirstVersion="20" />"#,
+                ),
+            )
+            .write(
+                "Meta/TestParam.xml",
+                r#"<PARAMMETA XmlVersion="0">
+                  <Self AlternativeOrder="third, -,
+                    second, first" />
+                  <Field>
+                    <first AltName="First Field" />
+                  </Field>
+                </PARAMMETA>"#,
+            );
+
+        let output = paramdex.generate();
+        let (latest, old) = output.split_once("pub mod before_20 {").unwrap();
+        assert!(
+            latest
+                .contains(r#"const DISPLAY_ORDER: &[&str] = &["third", "-", "second", "first"];"#)
+        );
+        assert!(latest.contains(r#"alt_name: Some("First Field"),"#));
+
+        // Older layouts leave out fields they don't have.
+        assert!(old.contains(r#"const DISPLAY_ORDER: &[&str] = &["-", "second", "first"];"#));
+    }
+
+    #[test]
+    fn row_names_with_collisions_and_non_ascii() {
+        let paramdex = Paramdex::new("row-names");
+        paramdex
+            .write(
+                "Defs/TestParam.xml",
+                &test_def(r#"<Field Def="s32 value" />"#),
+            )
+            .write(
+                "Names/TestParam.txt",
+                "30 Dagger\n10 Dagger\n20 Dagger\n40 短剣\n50 Miquella's Lily\n60 1st Gift\n",
+            );
+
+        let output = paramdex.generate();
+        assert!(output.contains("pub mod test_param {"));
+
+        // The lowest ID gets the plain name, and the rest get their IDs.
+        assert!(output.contains("pub const DAGGER: TestParamId = TestParamId(10);"));
+        assert!(output.contains("pub const DAGGER_20: TestParamId = TestParamId(20);"));
+        assert!(output.contains("pub const DAGGER_30: TestParamId = TestParamId(30);"));
+        assert!(output.contains("pub const MIQUELLAS_LILY: TestParamId = TestParamId(50);"));
+        assert!(output.contains("pub const ROW_1ST_GIFT: TestParamId = TestParamId(60);"));
+
+        // Names that aren't ASCII are only in the table.
+        assert!(output.contains(r#"(40, "短剣"),"#));
+        assert!(!output.contains("TestParamId(40)"));
+    }
+}
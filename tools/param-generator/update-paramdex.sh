@@ -0,0 +1,32 @@
+#!/bin/bash
+# Vendors paramdex's meta XML files and row names into each game crate's
+# `paramdex` folder, replacing the existing ones. The paramdefs in
+# `paramdex/Defs` are left alone, since changing them changes the generated
+# layouts.
+#
+# Usage: tools/param-generator/update-paramdex.sh [branch or tag]
+set -e
+
+ref=${1:-master}
+root=$(cd "$(dirname "$0")/../.." && pwd)
+checkout=$(mktemp -d)
+trap 'rm -rf "$checkout"' EXIT
+
+git clone --quiet --depth 1 --branch "$ref" --filter=blob:none --sparse \
+  https://github.com/soulsmods/Paramdex "$checkout"
+git -C "$checkout" sparse-checkout set ER/Meta ER/Names DS3/Meta DS3/Names NR/Meta NR/Names
+
+for game in ER:eldenring DS3:darksouls3 NR:nightreign; do
+  source=${game%%:*}
+  crate=${game#*:}
+  for folder in Meta Names; do
+    if [ -d "$checkout/$source/$folder" ]; then
+      rm -rf "$root/crates/$crate/paramdex/$folder"
+      cp -r "$checkout/$source/$folder" "$root/crates/$crate/paramdex/$folder"
+    else
+      echo "Paramdex has no $source/$folder" >&2
+    fi
+  done
+done
+
+echo "Vendored paramdex $(git -C "$checkout" rev-parse HEAD)"